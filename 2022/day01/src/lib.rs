@@ -0,0 +1,340 @@
+use std::cmp::Reverse;
+use std::io::{BufRead, Write};
+use std::num::IntErrorKind;
+
+use aoc_collections::TopK;
+use aoc_core::{Answer, Error, Flag, Part, Result, Solution};
+
+/// The food carried by one elf.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Elf {
+    /// Position of the elf in the input, counting from 1.
+    pub index: usize,
+    /// Calories of every food item, in input order.
+    pub items: Vec<u64>,
+    pub total: u64,
+}
+
+impl Elf {
+    /// Average calories per item, 0 for an elf without any.
+    pub fn mean(&self) -> f64 {
+        if self.items.is_empty() {
+            return 0.0;
+        }
+        self.total as f64 / self.items.len() as f64
+    }
+
+    /// Median calories per item, 0 for an elf without any.
+    pub fn median(&self) -> f64 {
+        let mut items = self.items.clone();
+        items.sort_unstable();
+
+        let mid = items.len() / 2;
+        match items.len() {
+            0 => 0.0,
+            len if len % 2 == 1 => items[mid] as f64,
+            _ => (items[mid - 1] as f64 + items[mid] as f64) / 2.0,
+        }
+    }
+}
+
+/// Whether `line` separates two elves. Lines holding only whitespace count
+/// too, as they are easy to miss in generated logs.
+fn is_separator(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Parses the calories of one food item on line `line_no`.
+fn parse_item(line: &str, line_no: usize) -> Result<u64> {
+    line.trim().parse().map_err(|e: std::num::ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow => Error::parse(line_no, format!("`{}` calories don't fit in 64 bits", line.trim())),
+        _ => Error::parse(line_no, format!("expected a number of calories, found `{}`", line)),
+    })
+}
+
+/// Adds `calories` from line `line_no` to the `total` of elf `index`.
+fn add_item(total: u64, calories: u64, index: usize, line_no: usize) -> Result<u64> {
+    total.checked_add(calories)
+        .ok_or_else(|| Error::parse(line_no, format!("elf {} carries more calories than fit in 64 bits", index)))
+}
+
+fn no_elves() -> Error {
+    Error::Input("there are no elves".to_owned())
+}
+
+/// Collects the items carried by each elf, in input order. Elves need at
+/// least one item, so blank lines after the last one or several blank lines
+/// in a row don't add empty elves.
+pub fn parse_elves(input: &str) -> Result<Vec<Elf>> {
+    let mut elves = Vec::new();
+    let mut elf = Elf { index: 1, items: Vec::new(), total: 0 };
+
+    for (idx, line) in input.lines().enumerate() {
+        if is_separator(line) {
+            if !elf.items.is_empty() {
+                let next = Elf { index: elf.index + 1, items: Vec::new(), total: 0 };
+                elves.push(std::mem::replace(&mut elf, next));
+            }
+        } else {
+            let calories = parse_item(line, idx + 1)?;
+            elf.total = add_item(elf.total, calories, elf.index, idx + 1)?;
+            elf.items.push(calories);
+        }
+    }
+
+    if !elf.items.is_empty() {
+        elves.push(elf);
+    }
+    if elves.is_empty() {
+        return Err(no_elves());
+    }
+
+    Ok(elves)
+}
+
+/// How far a [`stream_top_elves`] pass has got.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Progress {
+    pub bytes: u64,
+    pub lines: usize,
+    /// Elves found so far, including the one whose items are being read.
+    pub elves: usize,
+}
+
+/// Finds the `n` elves carrying the most calories in a single pass over
+/// `input`, in memory independent of its size, and returns them as
+/// `(elf, calories)` pairs like [`top_elves`].
+///
+/// Elves are told apart like in [`parse_elves`]. `progress` is called
+/// every `report_every` bytes or so, and once at the end.
+pub fn stream_top_elves(
+    input: &mut dyn BufRead,
+    n: usize,
+    report_every: u64,
+    mut progress: impl FnMut(Progress),
+) -> Result<Vec<(usize, u64)>> {
+    let mut top = TopK::new(n);
+    let mut state = Progress::default();
+    let mut next_report = report_every;
+    let mut total: u64 = 0;
+    let mut items = 0;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let read = input.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        state.bytes += read as u64;
+        state.lines += 1;
+
+        if is_separator(&line) {
+            if items > 0 {
+                top.push((total, Reverse(state.elves)));
+                total = 0;
+                items = 0;
+            }
+        } else {
+            if items == 0 {
+                state.elves += 1;
+            }
+            let calories = parse_item(line.trim_end_matches(['\n', '\r']), state.lines)?;
+            total = add_item(total, calories, state.elves, state.lines)?;
+            items += 1;
+        }
+
+        if state.bytes >= next_report {
+            progress(state);
+            next_report = state.bytes + report_every;
+        }
+    }
+
+    if items > 0 {
+        top.push((total, Reverse(state.elves)));
+    }
+    progress(state);
+    if state.elves == 0 {
+        return Err(no_elves());
+    }
+
+    Ok(top.into_sorted_vec()
+        .into_iter()
+        .map(|(total, Reverse(elf))| (elf, total))
+        .collect())
+}
+
+/// The `n` elves carrying the most calories, most calories first. Of elves
+/// carrying the same amount the first ones win.
+pub fn top_elves(elves: &[Elf], n: usize) -> Vec<&Elf> {
+    let mut top = TopK::new(n);
+    top.extend(elves.iter().map(|elf| (elf.total, Reverse(elf.index))));
+
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|(_, Reverse(index))| &elves[index - 1])
+        .collect()
+}
+
+/// All elves ordered by the calories they carry, most first, together
+/// with their rank. Elves carrying the same amount share a rank, and the
+/// next rank skips as many places as were shared.
+pub fn rank_elves(elves: &[Elf]) -> Vec<(usize, &Elf)> {
+    let mut ranked: Vec<&Elf> = elves.iter().collect();
+    ranked.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+
+    let mut ranking: Vec<(usize, &Elf)> = Vec::with_capacity(ranked.len());
+    for (idx, elf) in ranked.into_iter().enumerate() {
+        let rank = match ranking.last() {
+            Some(&(rank, previous)) if previous.total == elf.total => rank,
+            _ => idx + 1,
+        };
+        ranking.push((rank, elf));
+    }
+
+    ranking
+}
+
+/// Groups of elves carrying exactly the same amount of calories, as
+/// `(calories, elves)`, most calories first.
+pub fn ties(ranking: &[(usize, &Elf)]) -> Vec<(u64, Vec<usize>)> {
+    ranking.chunk_by(|(a, _), (b, _)| a == b)
+        .filter(|group| group.len() > 1)
+        .map(|group| (group[0].1.total, group.iter().map(|(_, elf)| elf.index).collect()))
+        .collect()
+}
+
+/// What part 2 reports.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Report {
+    /// The calories carried by the top three elves, as the puzzle asks.
+    #[default]
+    Sum,
+    /// The `n` elves carrying the most calories and their sum.
+    Top(usize),
+    /// Every elf ranked by calories, with per-elf statistics and ties.
+    Ranking,
+}
+
+#[derive(Default)]
+pub struct Day01 {
+    pub report: Report,
+    /// Sums up the input while reading it instead of parsing it whole,
+    /// which only works with reports that don't need every elf.
+    pub stream: bool,
+}
+
+impl Day01 {
+    /// How often progress is reported on stderr while streaming.
+    const PROGRESS_BYTES: u64 = 256 << 20;
+
+    fn report_top(top: &[(usize, u64)]) -> Answer {
+        let total: u64 = top.iter().map(|&(_, calories)| calories).sum();
+        let elves: Vec<Answer> = top.iter()
+            .map(|&(elf, calories)| Answer::record([("elf", Answer::from(elf)), ("calories", calories.into())]))
+            .collect();
+
+        Answer::record([("total", Answer::from(total)), ("elves", elves.into())])
+    }
+}
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "top", value: Some("N"), help: "list the N elves carrying the most calories in part 2" },
+        Flag { name: "ranking", value: None, help: "rank all elves with their statistics in part 2" },
+        Flag { name: "stream", value: None, help: "sum up the input while reading it, for huge inputs" },
+    ];
+
+    type Input = Vec<Elf>;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("top", Some(value)) => {
+                let top = value.parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| Error::Usage(format!("invalid number of elves `{}`", value)))?;
+                self.report = Report::Top(top);
+            }
+            ("ranking", None) => self.report = Report::Ranking,
+            ("stream", None) => self.stream = true,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_elves(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(top_elves(input, 1)[0].total.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        match self.report {
+            Report::Sum => Ok(top_elves(input, 3).iter().map(|elf| elf.total).sum::<u64>().into()),
+            Report::Top(n) => {
+                let top: Vec<(usize, u64)> = top_elves(input, n).iter().map(|elf| (elf.index, elf.total)).collect();
+                Ok(Self::report_top(&top))
+            }
+            Report::Ranking => {
+                let ranking = rank_elves(input);
+                let elves: Vec<Answer> = ranking.iter()
+                    .map(|&(rank, elf)| Answer::record([
+                        ("rank", Answer::from(rank)),
+                        ("elf", elf.index.into()),
+                        ("calories", elf.total.into()),
+                        ("items", elf.items.len().into()),
+                        ("mean", format!("{:.1}", elf.mean()).into()),
+                        ("median", format!("{:.1}", elf.median()).into()),
+                    ]))
+                    .collect();
+                let ties: Vec<Answer> = ties(&ranking).into_iter()
+                    .map(|(calories, elves)| Answer::record([("calories", Answer::from(calories)), ("elves", elves.into())]))
+                    .collect();
+
+                Ok(Answer::record([("elves", elves), ("ties", ties)]))
+            }
+        }
+    }
+
+    fn streaming(&self) -> bool {
+        self.stream
+    }
+
+    fn solve_stream(&self, input: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Answer>> {
+        let n = match self.report {
+            Report::Sum => 3,
+            Report::Top(n) => n.max(1),
+            Report::Ranking => {
+                return Err(Error::Usage("--ranking needs every elf and can't be combined with --stream".to_owned()));
+            }
+        };
+
+        let mut reported = false;
+        let top = stream_top_elves(input, n, Self::PROGRESS_BYTES, |progress| {
+            // Small inputs are done before the first report is due.
+            if reported || progress.bytes >= Self::PROGRESS_BYTES {
+                eprint!("\rread {} MiB, {} lines, {} elves", progress.bytes >> 20, progress.lines, progress.elves);
+                let _ = std::io::stderr().flush();
+                reported = true;
+            }
+        });
+        if reported {
+            eprintln!();
+        }
+        let top = top?;
+
+        Ok(parts.iter()
+            .map(|part| match (part, self.report) {
+                (Part::One, _) => top[0].1.into(),
+                (Part::Two, Report::Top(_)) => Self::report_top(&top),
+                (Part::Two, _) => top.iter().map(|&(_, calories)| calories).sum::<u64>().into(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day01::{Day01, Report};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day01::default(), EXAMPLE, &["24000", "45000"]);
+}
+
+#[test]
+fn example_top_elves() {
+    let day01 = Day01 { report: Report::Top(2), ..Day01::default() };
+
+    assert_answers(&day01, EXAMPLE, &["24000", "{total: 35000, elves: [{elf: 4, calories: 24000}, {elf: 3, calories: 11000}]}"]);
+}
+
+#[test]
+fn example_ranking() {
+    let day01 = Day01 { report: Report::Ranking, ..Day01::default() };
+
+    assert_answers(&day01, EXAMPLE, &["24000", "{elves: [\
+        {rank: 1, elf: 4, calories: 24000, items: 3, mean: 8000.0, median: 8000.0}, \
+        {rank: 2, elf: 3, calories: 11000, items: 2, mean: 5500.0, median: 5500.0}, \
+        {rank: 3, elf: 5, calories: 10000, items: 1, mean: 10000.0, median: 10000.0}, \
+        {rank: 4, elf: 1, calories: 6000, items: 3, mean: 2000.0, median: 2000.0}, \
+        {rank: 5, elf: 2, calories: 4000, items: 1, mean: 4000.0, median: 4000.0}], \
+        ties: []}"]);
+}
+
+#[test]
+fn ranking_shares_ranks_between_ties() {
+    let day01 = Day01 { report: Report::Ranking, ..Day01::default() };
+
+    assert_answers(&day01, "1\n2\n\n3\n\n3\n\n1", &["3", "{elves: [\
+        {rank: 1, elf: 1, calories: 3, items: 2, mean: 1.5, median: 1.5}, \
+        {rank: 1, elf: 2, calories: 3, items: 1, mean: 3.0, median: 3.0}, \
+        {rank: 1, elf: 3, calories: 3, items: 1, mean: 3.0, median: 3.0}, \
+        {rank: 4, elf: 4, calories: 1, items: 1, mean: 1.0, median: 1.0}], \
+        ties: [{calories: 3, elves: [1, 2, 3]}]}"]);
+}
+
+#[test]
+fn skips_blank_lines_without_elves() {
+    let day01 = Day01 { report: Report::Ranking, ..Day01::default() };
+
+    let ranking = "{elves: [\
+        {rank: 1, elf: 2, calories: 4000, items: 1, mean: 4000.0, median: 4000.0}, \
+        {rank: 2, elf: 1, calories: 3000, items: 2, mean: 1500.0, median: 1500.0}], \
+        ties: []}";
+    assert_answers(&day01, "1000\n2000\n\n4000\n\n", &["4000", ranking]);
+    assert_answers(&day01, "\n1000\n2000\n\n\n  \n4000\n", &["4000", ranking]);
+}
+
+#[test]
+fn needs_an_elf() {
+    for input in ["", "\n\n", " \n"] {
+        assert!(matches!(Day01::default().run(input, &Part::ALL), Err(Error::Input(_))));
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::Cursor;
+
+use aoc_core::{Error, Part, Runnable};
+use day01::{stream_top_elves, Day01, Progress, Report};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+fn stream(day01: &Day01, input: &str) -> Result<Vec<String>, Error> {
+    let records = day01.run_stream(&mut Cursor::new(input), &Part::ALL)?;
+    Ok(records.iter().map(|record| record.answer.to_string()).collect())
+}
+
+#[test]
+fn streams_the_example() {
+    let day01 = Day01 { stream: true, ..Day01::default() };
+    assert_eq!(stream(&day01, EXAMPLE).unwrap(), ["24000", "45000"]);
+
+    let day01 = Day01 { stream: true, report: Report::Top(2) };
+    assert_eq!(
+        stream(&day01, EXAMPLE).unwrap(),
+        ["24000", "{total: 35000, elves: [{elf: 4, calories: 24000}, {elf: 3, calories: 11000}]}"]
+    );
+}
+
+#[test]
+fn handles_crlf_and_blank_separators() {
+    let input = EXAMPLE.replace('\n', "\r\n").replace("\r\n\r\n", "\r\n  \t\r\n");
+    let day01 = Day01 { stream: true, ..Day01::default() };
+
+    assert_eq!(stream(&day01, &input).unwrap(), ["24000", "45000"]);
+}
+
+#[test]
+fn detects_overflow() {
+    let input = format!("1\n\n{}\n1\n", u64::MAX);
+    let day01 = Day01 { stream: true, ..Day01::default() };
+
+    assert!(matches!(stream(&day01, &input), Err(Error::Parse { line: 4, .. })));
+    assert!(matches!(stream(&day01, "1\n\n18446744073709551616\n"), Err(Error::Parse { line: 3, .. })));
+}
+
+#[test]
+fn can_not_rank_while_streaming() {
+    let day01 = Day01 { stream: true, report: Report::Ranking };
+
+    assert!(matches!(stream(&day01, EXAMPLE), Err(Error::Usage(_))));
+}
+
+#[test]
+fn reports_progress() {
+    let mut reports = Vec::new();
+    let top = stream_top_elves(&mut Cursor::new(EXAMPLE), 1, 20, |progress| reports.push(progress)).unwrap();
+
+    assert_eq!(top, [(4, 24000)]);
+    assert!(reports.len() > 1);
+    assert_eq!(reports.last(), Some(&Progress { bytes: EXAMPLE.len() as u64, lines: 14, elves: 5 }));
+}
+
+#[test]
+fn skips_blank_lines_without_elves_like_parsing() {
+    let inputs = ["1000\n2000\n\n3000\n\n", "\n\n1\n\n\n\n2\n  \n\n3\n\n4\n\n", "5\n\n\n\n5\n\n", EXAMPLE];
+
+    for input in inputs {
+        for report in [Report::Sum, Report::Top(3), Report::Top(10)] {
+            let parsed = Day01 { report, stream: false }.run(input, &Part::ALL).unwrap();
+            let parsed: Vec<String> = parsed.iter().map(|record| record.answer.to_string()).collect();
+
+            assert_eq!(stream(&Day01 { report, stream: true }, input).unwrap(), parsed, "{:?}", input);
+        }
+    }
+
+    let day01 = Day01 { stream: true, report: Report::Top(10) };
+    assert_eq!(
+        stream(&day01, "1000\n2000\n\n3000\n\n").unwrap(),
+        ["3000", "{total: 6000, elves: [{elf: 1, calories: 3000}, {elf: 2, calories: 3000}]}"]
+    );
+
+    let mut last = None;
+    stream_top_elves(&mut Cursor::new("\n1\n\n\n2\n\n"), 1, 1, |progress| last = Some(progress)).unwrap();
+    assert_eq!(last.map(|progress| progress.elves), Some(2));
+}
+
+#[test]
+fn needs_an_elf() {
+    let day01 = Day01 { stream: true, ..Day01::default() };
+
+    assert!(matches!(stream(&day01, ""), Err(Error::Input(_))));
+    assert!(matches!(stream(&day01, "\n \n\n"), Err(Error::Input(_))));
+}
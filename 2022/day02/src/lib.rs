@@ -0,0 +1,344 @@
+mod round;
+mod rules;
+
+pub use round::{parse_rounds, GuideLine, Round, RoundError};
+pub use rules::{Rules, RulesError, Shape};
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+
+#[derive(Clone, Copy, Eq, Debug, PartialEq)]
+pub enum ExpectedResult {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl ExpectedResult {
+    pub const ALL: [ExpectedResult; 3] = [ExpectedResult::Lose, ExpectedResult::Draw, ExpectedResult::Win];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ExpectedResult::Lose => "lose",
+            ExpectedResult::Draw => "draw",
+            ExpectedResult::Win => "win",
+        }
+    }
+}
+
+/// Which shape every player symbol stands for, indexed like the shapes of
+/// the rules. The puzzle maps every symbol to its own shape.
+pub type ShapeMapping = Vec<usize>;
+
+/// Which outcome every outcome symbol stands for, indexed by
+/// [`ExpectedResult`]. The puzzle maps every symbol to its own outcome.
+pub type OutcomeMapping = [ExpectedResult; 3];
+
+pub fn identity_shapes(rules: &Rules) -> ShapeMapping {
+    (0..rules.shapes().len()).collect()
+}
+
+pub const IDENTITY_OUTCOMES: OutcomeMapping = ExpectedResult::ALL;
+
+pub fn calculate_score(rules: &Rules, opponent: usize, player: usize) -> u64 {
+    rules.shape_score(player) + rules.outcome_score(rules.outcome(opponent, player))
+}
+
+/// The opponent's shape and our symbol's shape of every round, failing on
+/// the first round whose symbol isn't a shape.
+pub fn shape_plays(rounds: &[Round]) -> Result<Vec<(usize, usize)>> {
+    rounds.iter().map(|round| Ok((round.opponent, round.player()?))).collect()
+}
+
+/// The opponent's shape and our symbol's outcome of every round, failing on
+/// the first round whose symbol isn't an outcome.
+pub fn outcome_plays(rounds: &[Round]) -> Result<Vec<(usize, ExpectedResult)>> {
+    rounds.iter().map(|round| Ok((round.opponent, round.expected_result()?))).collect()
+}
+
+/// Total score of playing the shapes the guide says, reading player symbols
+/// through `mapping`.
+pub fn total_score(rules: &Rules, plays: &[(usize, usize)], mapping: &[usize]) -> u64 {
+    plays.iter()
+        .map(|&(opponent, player)| calculate_score(rules, opponent, mapping[player]))
+        .sum()
+}
+
+/// The shape that gets `outcome` against `opponent`. Rules can leave a
+/// round without one, for example when a shape beats every other one.
+pub fn shape_for(rules: &Rules, opponent: usize, outcome: ExpectedResult) -> Result<usize> {
+    rules.what_to_play(opponent, outcome).ok_or_else(|| Error::Input(format!(
+        "no shape can {} against `{}`",
+        outcome.name(),
+        rules.shapes()[opponent].name
+    )))
+}
+
+/// Score of getting `outcome` against `opponent`.
+pub fn calculate_score_p2(rules: &Rules, opponent: usize, outcome: ExpectedResult) -> Result<u64> {
+    Ok(rules.shape_score(shape_for(rules, opponent, outcome)?) + rules.outcome_score(outcome))
+}
+
+/// Total score of getting the outcomes the guide says, reading outcome
+/// symbols through `mapping`.
+pub fn total_score_p2(rules: &Rules, plays: &[(usize, ExpectedResult)], mapping: &OutcomeMapping) -> Result<u64> {
+    plays.iter()
+        .map(|&(opponent, expected_result)| calculate_score_p2(rules, opponent, mapping[expected_result as usize]))
+        .sum()
+}
+
+/// All orderings of `0..n`, starting with the identity and in
+/// lexicographic order.
+pub fn permutations(n: usize) -> Vec<Vec<usize>> {
+    fn extend(prefix: &mut Vec<usize>, used: &mut [bool], all: &mut Vec<Vec<usize>>) {
+        if prefix.len() == used.len() {
+            all.push(prefix.clone());
+            return;
+        }
+        for next in 0..used.len() {
+            if !used[next] {
+                used[next] = true;
+                prefix.push(next);
+                extend(prefix, used, all);
+                prefix.pop();
+                used[next] = false;
+            }
+        }
+    }
+
+    let mut all = Vec::new();
+    extend(&mut Vec::with_capacity(n), &mut vec![false; n], &mut all);
+    all
+}
+
+/// The mappings scoring the least and the most, with their totals. Of
+/// mappings scoring the same, the first one wins.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extremes<M> {
+    pub min: (M, u64),
+    pub max: (M, u64),
+}
+
+/// Scores every mapping with `total` and keeps the extremes, or returns
+/// `None` if there are no mappings.
+pub fn optimize<M: Clone>(mappings: impl IntoIterator<Item = M>, total: impl Fn(&M) -> u64) -> Option<Extremes<M>> {
+    let mut extremes: Option<Extremes<M>> = None;
+
+    for mapping in mappings {
+        let score = total(&mapping);
+        match &mut extremes {
+            None => extremes = Some(Extremes { min: (mapping.clone(), score), max: (mapping, score) }),
+            Some(e) => {
+                if score < e.min.1 {
+                    e.min = (mapping.clone(), score);
+                }
+                if score > e.max.1 {
+                    e.max = (mapping, score);
+                }
+            }
+        }
+    }
+
+    extremes
+}
+
+pub fn optimize_shapes(rules: &Rules, plays: &[(usize, usize)]) -> Extremes<ShapeMapping> {
+    optimize(permutations(rules.shapes().len()), |mapping| total_score(rules, plays, mapping))
+        .expect("rules have at least one shape")
+}
+
+/// Fails if some mapping asks for an outcome no shape gets, see
+/// [`shape_for`].
+pub fn optimize_outcomes(rules: &Rules, plays: &[(usize, ExpectedResult)]) -> Result<Extremes<OutcomeMapping>> {
+    // Every mapping reads every symbol as one of the outcomes, so score them
+    // all once.
+    let scores = plays.iter()
+        .map(|&(opponent, expected_result)| {
+            let mut scores = [0; 3];
+            for outcome in ExpectedResult::ALL {
+                scores[outcome as usize] = calculate_score_p2(rules, opponent, outcome)?;
+            }
+            Ok((expected_result, scores))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let all = ExpectedResult::ALL;
+    let mappings = permutations(all.len()).into_iter().map(|p| [all[p[0]], all[p[1]], all[p[2]]]);
+    let total = |mapping: &OutcomeMapping| -> u64 {
+        scores.iter().map(|(expected_result, scores)| scores[mapping[*expected_result as usize] as usize]).sum()
+    };
+
+    Ok(optimize(mappings, total).expect("there are outcome mappings"))
+}
+
+/// A round as actually played, for the breakdown of a part.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Play {
+    pub line: usize,
+    pub opponent: usize,
+    pub player: usize,
+    pub outcome: ExpectedResult,
+}
+
+impl Play {
+    pub fn shape_points(&self, rules: &Rules) -> u64 {
+        rules.shape_score(self.player)
+    }
+
+    pub fn outcome_points(&self, rules: &Rules) -> u64 {
+        rules.outcome_score(self.outcome)
+    }
+}
+
+/// The rounds of part 1, playing the shapes the guide says.
+pub fn plays_p1(rules: &Rules, rounds: &[Round]) -> Result<Vec<Play>> {
+    rounds.iter()
+        .map(|round| {
+            let player = round.player()?;
+            let outcome = rules.outcome(round.opponent, player);
+            Ok(Play { line: round.line, opponent: round.opponent, player, outcome })
+        })
+        .collect()
+}
+
+/// The rounds of part 2, getting the outcomes the guide says. Fails on the
+/// first round no shape gets the outcome of, see [`shape_for`].
+pub fn plays_p2(rules: &Rules, rounds: &[Round]) -> Result<Vec<Play>> {
+    rounds.iter()
+        .map(|round| {
+            let outcome = round.expected_result()?;
+            let player = shape_for(rules, round.opponent, outcome)?;
+            Ok(Play { line: round.line, opponent: round.opponent, player, outcome })
+        })
+        .collect()
+}
+
+/// Reports every round of a part with its points, how many rounds ended
+/// which way and how often every shape was played.
+pub fn breakdown(rules: &Rules, plays: &[Play]) -> Answer {
+    let shape_name = |shape: usize| rules.shapes()[shape].name.as_str();
+
+    let rounds: Vec<Answer> = plays.iter()
+        .map(|play| Answer::record([
+            ("line", Answer::from(play.line)),
+            ("opponent", shape_name(play.opponent).into()),
+            ("player", shape_name(play.player).into()),
+            ("outcome", play.outcome.name().into()),
+            ("shape_points", play.shape_points(rules).into()),
+            ("outcome_points", play.outcome_points(rules).into()),
+        ]))
+        .collect();
+
+    let total: u64 = plays.iter().map(|play| play.shape_points(rules) + play.outcome_points(rules)).sum();
+
+    let outcomes = [ExpectedResult::Win, ExpectedResult::Draw, ExpectedResult::Lose].map(|outcome| {
+        (outcome.name(), plays.iter().filter(|play| play.outcome == outcome).count())
+    });
+    let shapes = (0..rules.shapes().len()).map(|shape| {
+        (shape_name(shape), plays.iter().filter(|play| play.player == shape).count())
+    });
+
+    Answer::record([
+        ("total", Answer::from(total)),
+        ("outcomes", Answer::record(outcomes)),
+        ("shapes", Answer::record(shapes)),
+        ("rounds", rounds.into()),
+    ])
+}
+
+fn shape_mapping_answer(rules: &Rules, (mapping, total): &(ShapeMapping, u64)) -> Answer {
+    let shapes = rules.shapes();
+    let mapping = shapes.iter()
+        .zip(mapping)
+        .map(|(symbol, &shape)| (symbol.player.as_str(), shapes[shape].name.as_str()));
+
+    Answer::record([("total", Answer::from(*total)), ("mapping", Answer::record(mapping))])
+}
+
+fn outcome_mapping_answer(rules: &Rules, (mapping, total): &(OutcomeMapping, u64)) -> Answer {
+    let mapping = ExpectedResult::ALL.iter()
+        .zip(mapping)
+        .map(|(&symbol, outcome)| (rules.outcome_symbol(symbol), outcome.name()));
+
+    Answer::record([("total", Answer::from(*total)), ("mapping", Answer::record(mapping))])
+}
+
+/// What the parts report.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Report {
+    /// The total score, as the puzzle asks.
+    #[default]
+    Total,
+    /// The readings of the guide that score the most and the least.
+    Optimize,
+    /// The total score with every round and statistics, see [`breakdown`].
+    Breakdown,
+}
+
+#[derive(Default)]
+pub struct Day02 {
+    pub rules: Rules,
+    pub report: Report,
+}
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "rules", value: Some("file"), help: "play by the rules in a TOML file, see 2022/day02/rules" },
+        Flag { name: "optimize", value: None, help: "find the readings of the guide scoring the most and least" },
+        Flag { name: "breakdown", value: None, help: "report every round, outcome counts and shapes played" },
+    ];
+
+    type Input = Vec<Round>;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("rules", Some(path)) => self.rules = Rules::load(path)?,
+            ("optimize", None) => self.report = Report::Optimize,
+            ("breakdown", None) => self.report = Report::Breakdown,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_rounds(&self.rules, input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        if self.report == Report::Breakdown {
+            return Ok(breakdown(&self.rules, &plays_p1(&self.rules, input)?));
+        }
+
+        let plays = shape_plays(input)?;
+
+        if self.report == Report::Total {
+            return Ok(total_score(&self.rules, &plays, &identity_shapes(&self.rules)).into());
+        }
+
+        let extremes = optimize_shapes(&self.rules, &plays);
+        Ok(Answer::record([
+            ("max", shape_mapping_answer(&self.rules, &extremes.max)),
+            ("min", shape_mapping_answer(&self.rules, &extremes.min)),
+        ]))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        if self.report == Report::Breakdown {
+            return Ok(breakdown(&self.rules, &plays_p2(&self.rules, input)?));
+        }
+
+        let plays = outcome_plays(input)?;
+
+        if self.report == Report::Total {
+            return Ok(total_score_p2(&self.rules, &plays, &IDENTITY_OUTCOMES)?.into());
+        }
+
+        let extremes = optimize_outcomes(&self.rules, &plays)?;
+        Ok(Answer::record([
+            ("max", outcome_mapping_answer(&self.rules, &extremes.max)),
+            ("min", outcome_mapping_answer(&self.rules, &extremes.min)),
+        ]))
+    }
+}
@@ -0,0 +1,108 @@
+//! Decoding the lines of the strategy guide.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aoc_core::{Error, Result};
+
+use crate::{ExpectedResult, Rules};
+
+/// Why a line of the strategy guide can't be read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundError {
+    /// The line doesn't have exactly two columns.
+    Columns(usize),
+    /// The first column isn't an opponent symbol of the rules.
+    UnknownOpponent(String),
+    /// The second column is neither a player nor an outcome symbol.
+    UnknownSymbol(String),
+    /// Part 1 needs the second column to be a player symbol.
+    NotAShape(String),
+    /// Part 2 needs the second column to be an outcome symbol.
+    NotAnOutcome(String),
+}
+
+impl Display for RoundError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundError::Columns(found) => write!(f, "expected 2 columns, found {}", found),
+            RoundError::UnknownOpponent(symbol) => write!(f, "`{}` isn't an opponent's shape", symbol),
+            RoundError::UnknownSymbol(symbol) => write!(f, "`{}` is neither a shape nor an outcome", symbol),
+            RoundError::NotAShape(symbol) => write!(f, "`{}` isn't a shape to play", symbol),
+            RoundError::NotAnOutcome(symbol) => write!(f, "`{}` isn't an outcome", symbol),
+        }
+    }
+}
+
+impl std::error::Error for RoundError {}
+
+/// The two columns of a line of the strategy guide, not decoded yet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuideLine {
+    pub opponent: String,
+    pub guide: String,
+}
+
+impl FromStr for GuideLine {
+    type Err = RoundError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>()[..] {
+            [opponent, guide] => Ok(GuideLine { opponent: opponent.to_owned(), guide: guide.to_owned() }),
+            ref columns => Err(RoundError::Columns(columns.len())),
+        }
+    }
+}
+
+/// One round of the strategy guide, with our column read both as the shape
+/// to play for part 1 and as the outcome to get for part 2, where the rules
+/// allow it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    /// Line of the round in the guide, counting from 1.
+    pub line: usize,
+    pub opponent: usize,
+    /// Our column as it appears in the guide.
+    pub guide: String,
+    pub player: Option<usize>,
+    pub expected_result: Option<ExpectedResult>,
+}
+
+impl Round {
+    /// Decodes `line` with the symbols of `rules`.
+    pub fn decode(rules: &Rules, line: &GuideLine, line_no: usize) -> Result<Self, RoundError> {
+        let opponent = rules.opponent_shape(&line.opponent)
+            .ok_or_else(|| RoundError::UnknownOpponent(line.opponent.clone()))?;
+        let player = rules.player_shape(&line.guide);
+        let expected_result = rules.player_outcome(&line.guide);
+
+        if player.is_none() && expected_result.is_none() {
+            return Err(RoundError::UnknownSymbol(line.guide.clone()));
+        }
+
+        Ok(Round { line: line_no, opponent, guide: line.guide.clone(), player, expected_result })
+    }
+
+    /// The shape to play in part 1.
+    pub fn player(&self) -> Result<usize> {
+        self.player.ok_or_else(|| Error::parse(self.line, RoundError::NotAShape(self.guide.clone()).to_string()))
+    }
+
+    /// The outcome to get in part 2.
+    pub fn expected_result(&self) -> Result<ExpectedResult> {
+        self.expected_result
+            .ok_or_else(|| Error::parse(self.line, RoundError::NotAnOutcome(self.guide.clone()).to_string()))
+    }
+}
+
+pub fn parse_rounds(rules: &Rules, input: &str) -> Result<Vec<Round>> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .and_then(|line| Round::decode(rules, &line, idx + 1))
+                .map_err(|e| Error::parse(idx + 1, e.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,256 @@
+//! Rule tables for rock-paper-scissors like games.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+
+use serde::Deserialize;
+
+use aoc_core::{Error, Result};
+
+use crate::ExpectedResult;
+
+/// One of the shapes a player can throw.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shape {
+    pub name: String,
+    /// Symbol of the shape in the opponent's column of the strategy guide.
+    pub opponent: String,
+    /// Symbol of the shape in our column, as read by part 1.
+    pub player: String,
+    pub score: u64,
+}
+
+/// Shapes, which of them beat which, and how rounds are scored.
+///
+/// Shapes are referred to by their index in [`Rules::shapes`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rules {
+    shapes: Vec<Shape>,
+    /// `beats[a][b]` tells whether shape `a` beats shape `b`.
+    beats: Vec<Vec<bool>>,
+    /// Symbols of the outcomes as read by part 2, and their scores, indexed
+    /// by [`ExpectedResult`].
+    outcome_symbols: [String; 3],
+    outcome_scores: [u64; 3],
+}
+
+/// Why a text isn't a valid set of rules.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RulesError {
+    /// The text isn't TOML, or doesn't have the fields of the format.
+    /// `position` is the line and column the error starts at, counted from
+    /// 1, if `toml` can tell.
+    Toml { position: Option<(usize, usize)>, message: String },
+    NoShapes,
+    /// A shape name or a symbol is used twice, `what` telling which.
+    Duplicate { what: &'static str, value: String },
+    /// `winner` beats `loser`, which isn't the name of a shape.
+    UnknownShape { winner: String, loser: String },
+    BeatsItself(String),
+    BeatEachOther(String, String),
+    /// Neither shape beats the other.
+    Unrelated(String, String),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Toml { position: Some((line, column)), message } => {
+                write!(f, "line {}, column {}: {}", line, column, message)
+            }
+            RulesError::Toml { position: None, message } => write!(f, "{}", message),
+            RulesError::NoShapes => write!(f, "there are no shapes"),
+            RulesError::Duplicate { what, value } => write!(f, "{} `{}` is used twice", what, value),
+            RulesError::UnknownShape { winner, loser } => {
+                write!(f, "`{}` beats `{}`, which isn't a shape", winner, loser)
+            }
+            RulesError::BeatsItself(shape) => write!(f, "`{}` beats itself", shape),
+            RulesError::BeatEachOther(a, b) => write!(f, "`{}` and `{}` beat each other", a, b),
+            RulesError::Unrelated(a, b) => write!(f, "neither `{}` nor `{}` beats the other", a, b),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    outcomes: OutcomesFile,
+    shapes: Vec<ShapeFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomesFile {
+    lose: OutcomeFile,
+    draw: OutcomeFile,
+    win: OutcomeFile,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomeFile {
+    symbol: String,
+    score: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShapeFile {
+    name: String,
+    opponent: String,
+    player: String,
+    score: u64,
+    #[serde(default)]
+    beats: Vec<String>,
+}
+
+/// The line and column of the byte at `offset` in `text`, counted from 1.
+fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..text.floor_char_boundary(offset)];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+fn check_unique<'a>(what: &'static str, values: impl Iterator<Item = &'a str>) -> Result<(), RulesError> {
+    let mut seen = HashSet::new();
+
+    for value in values {
+        if !seen.insert(value) {
+            return Err(RulesError::Duplicate { what, value: value.to_owned() });
+        }
+    }
+
+    Ok(())
+}
+
+impl Rules {
+    /// The rules of the puzzle.
+    pub fn standard() -> Self {
+        Self::from_toml(include_str!("../rules/rps.toml")).expect("the bundled rules are valid")
+    }
+
+    /// Reads rules in the format of `rules/rps.toml`.
+    ///
+    /// Every two different shapes have to be related, exactly one of them
+    /// beating the other.
+    pub fn from_toml(text: &str) -> Result<Self, RulesError> {
+        let file: RulesFile = toml::from_str(text).map_err(|e| RulesError::Toml {
+            position: e.span().map(|span| position(text, span.start)),
+            message: e.message().to_owned(),
+        })?;
+
+        if file.shapes.is_empty() {
+            return Err(RulesError::NoShapes);
+        }
+        check_unique("shape", file.shapes.iter().map(|s| s.name.as_str()))?;
+        check_unique("opponent symbol", file.shapes.iter().map(|s| s.opponent.as_str()))?;
+        check_unique("player symbol", file.shapes.iter().map(|s| s.player.as_str()))?;
+        let outcomes = [file.outcomes.lose, file.outcomes.draw, file.outcomes.win];
+        check_unique("outcome symbol", outcomes.iter().map(|o| o.symbol.as_str()))?;
+
+        let index: HashMap<&str, usize> = file.shapes.iter()
+            .enumerate()
+            .map(|(idx, shape)| (shape.name.as_str(), idx))
+            .collect();
+
+        let mut beats = vec![vec![false; file.shapes.len()]; file.shapes.len()];
+        for (winner, shape) in file.shapes.iter().enumerate() {
+            for name in &shape.beats {
+                let loser = *index.get(name.as_str()).ok_or_else(|| RulesError::UnknownShape {
+                    winner: shape.name.clone(),
+                    loser: name.clone(),
+                })?;
+                if loser == winner {
+                    return Err(RulesError::BeatsItself(name.clone()));
+                }
+                beats[winner][loser] = true;
+            }
+        }
+
+        for (a, x) in file.shapes.iter().enumerate() {
+            for (b, y) in file.shapes.iter().enumerate().skip(a + 1) {
+                match (beats[a][b], beats[b][a]) {
+                    (true, true) => return Err(RulesError::BeatEachOther(x.name.clone(), y.name.clone())),
+                    (false, false) => return Err(RulesError::Unrelated(x.name.clone(), y.name.clone())),
+                    _ => {}
+                }
+            }
+        }
+
+        let [lose, draw, win] = outcomes;
+
+        Ok(Self {
+            shapes: file.shapes.into_iter()
+                .map(|s| Shape { name: s.name, opponent: s.opponent, player: s.player, score: s.score })
+                .collect(),
+            beats,
+            outcome_symbols: [lose.symbol, draw.symbol, win.symbol],
+            outcome_scores: [lose.score, draw.score, win.score],
+        })
+    }
+
+    /// Reads rules from the file at `path`, see [`Rules::from_toml`].
+    pub fn load(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| Error::Io(io::Error::new(e.kind(), format!("can't read rules from {}: {}", path, e))))?;
+
+        Self::from_toml(&text).map_err(|e| Error::Input(format!("invalid rules in {}: {}", path, e)))
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn opponent_shape(&self, symbol: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.opponent == symbol)
+    }
+
+    pub fn player_shape(&self, symbol: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.player == symbol)
+    }
+
+    pub fn player_outcome(&self, symbol: &str) -> Option<ExpectedResult> {
+        ExpectedResult::ALL.into_iter().find(|&outcome| self.outcome_symbols[outcome as usize] == symbol)
+    }
+
+    pub fn outcome_symbol(&self, outcome: ExpectedResult) -> &str {
+        &self.outcome_symbols[outcome as usize]
+    }
+
+    /// How a round ends for the player throwing `player` against `opponent`.
+    pub fn outcome(&self, opponent: usize, player: usize) -> ExpectedResult {
+        if self.beats[player][opponent] {
+            ExpectedResult::Win
+        } else if self.beats[opponent][player] {
+            ExpectedResult::Lose
+        } else {
+            ExpectedResult::Draw
+        }
+    }
+
+    /// The first shape, in the order of the rules, that gets `outcome`
+    /// against `opponent`, if there is one.
+    pub fn what_to_play(&self, opponent: usize, outcome: ExpectedResult) -> Option<usize> {
+        (0..self.shapes.len()).find(|&player| self.outcome(opponent, player) == outcome)
+    }
+
+    pub fn shape_score(&self, shape: usize) -> u64 {
+        self.shapes[shape].score
+    }
+
+    pub fn outcome_score(&self, outcome: ExpectedResult) -> u64 {
+        self.outcome_scores[outcome as usize]
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_core::{Error, Part, Runnable};
+use day02::{Day02, GuideLine, Rules, RoundError};
+
+fn parse_error(input: &str) -> (usize, String) {
+    match Day02::default().run(input, &Part::ALL) {
+        Err(Error::Parse { line, message, .. }) => (line, message),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn reads_two_columns() {
+    assert_eq!("A Y".parse(), Ok(GuideLine { opponent: "A".to_owned(), guide: "Y".to_owned() }));
+    assert_eq!("A".parse::<GuideLine>(), Err(RoundError::Columns(1)));
+    assert_eq!("A Y Z".parse::<GuideLine>(), Err(RoundError::Columns(3)));
+    assert_eq!("".parse::<GuideLine>(), Err(RoundError::Columns(0)));
+}
+
+#[test]
+fn reports_bad_lines() {
+    assert_eq!(parse_error("A Y\nB\nC Z"), (2, "expected 2 columns, found 1".to_owned()));
+    assert_eq!(parse_error("A Y\nB X\n\nC Z"), (3, "expected 2 columns, found 0".to_owned()));
+    assert_eq!(parse_error("A Y\nD X"), (2, "`D` isn't an opponent's shape".to_owned()));
+    assert_eq!(parse_error("A Y\nB X\nC W"), (3, "`W` is neither a shape nor an outcome".to_owned()));
+}
+
+#[test]
+fn reports_symbols_one_part_can_not_read() {
+    let day02 = Day02 { rules: Rules::from_toml(include_str!("../rules/rpsls.toml")).unwrap(), ..Day02::default() };
+    let input = "A X\nB U";
+
+    assert!(day02.run(input, &[Part::One]).is_ok());
+    match day02.run(input, &[Part::Two]) {
+        Err(Error::Parse { line: 2, message, .. }) => assert_eq!(message, "`U` isn't an outcome"),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
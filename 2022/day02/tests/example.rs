@@ -0,0 +1,25 @@
+use aoc_core::testing::assert_answers;
+use day02::{Day02, Report};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day02::default(), EXAMPLE, &["15", "12"]);
+}
+
+#[test]
+fn example_breakdown() {
+    let day02 = Day02 { report: Report::Breakdown, ..Day02::default() };
+
+    assert_answers(&day02, EXAMPLE, &[
+        "{total: 15, outcomes: {win: 1, draw: 1, lose: 1}, shapes: {rock: 1, paper: 1, scissors: 1}, rounds: [\
+            {line: 1, opponent: rock, player: paper, outcome: win, shape_points: 2, outcome_points: 6}, \
+            {line: 2, opponent: paper, player: rock, outcome: lose, shape_points: 1, outcome_points: 0}, \
+            {line: 3, opponent: scissors, player: scissors, outcome: draw, shape_points: 3, outcome_points: 3}]}",
+        "{total: 12, outcomes: {win: 1, draw: 1, lose: 1}, shapes: {rock: 3, paper: 0, scissors: 0}, rounds: [\
+            {line: 1, opponent: rock, player: rock, outcome: draw, shape_points: 1, outcome_points: 3}, \
+            {line: 2, opponent: paper, player: rock, outcome: lose, shape_points: 1, outcome_points: 0}, \
+            {line: 3, opponent: scissors, player: rock, outcome: win, shape_points: 1, outcome_points: 6}]}",
+    ]);
+}
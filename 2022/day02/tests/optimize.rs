@@ -0,0 +1,37 @@
+use aoc_core::testing::assert_answers;
+use day02::{identity_shapes, optimize_shapes, parse_rounds, permutations, shape_plays, total_score, Day02, Report, Rules};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn permutations_start_with_the_identity() {
+    let all = permutations(3);
+
+    assert_eq!(all, [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]]);
+    assert_eq!(permutations(5).len(), 120);
+}
+
+#[test]
+fn optimizes_the_example() {
+    let day02 = Day02 { report: Report::Optimize, ..Day02::default() };
+
+    assert_answers(&day02, EXAMPLE, &[
+        "{max: {total: 24, mapping: {X: scissors, Y: paper, Z: rock}}, \
+          min: {total: 6, mapping: {X: rock, Y: scissors, Z: paper}}}",
+        "{max: {total: 18, mapping: {X: win, Y: lose, Z: draw}}, \
+          min: {total: 12, mapping: {X: lose, Y: draw, Z: win}}}",
+    ]);
+}
+
+#[test]
+fn puzzle_reading_is_within_the_extremes() {
+    let rules = Rules::from_toml(include_str!("../rules/rpsls.toml")).unwrap();
+    let rounds = parse_rounds(&rules, "A X\nE Y\nC U\nD V\nB Z\nE X").unwrap();
+    let plays = shape_plays(&rounds).unwrap();
+
+    let extremes = optimize_shapes(&rules, &plays);
+    let puzzle = total_score(&rules, &plays, &identity_shapes(&rules));
+
+    assert!(extremes.min.1 <= puzzle && puzzle <= extremes.max.1);
+    assert_eq!(total_score(&rules, &plays, &extremes.max.0), extremes.max.1);
+}
@@ -0,0 +1,136 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day02::{Day02, ExpectedResult, Report, Rules, RulesError};
+
+const RPSLS: &str = include_str!("../rules/rpsls.toml");
+
+#[test]
+fn plays_by_custom_rules() {
+    let day02 = Day02 { rules: Rules::from_toml(RPSLS).unwrap(), ..Day02::default() };
+
+    // Spock smashes scissors: 3 + 0. Rock crushes lizard: 1 + 6. Paper
+    // disproves Spock: 2 + 6.
+    // Part 2 beats Spock with paper: 2 + 6, loses to lizard with paper, the
+    // first shape it beats: 2 + 0, and draws against Spock: 5 + 3.
+    assert_answers(&day02, "E Z\nD X\nE Y", &["18", "18"]);
+
+    // Lizard poisons Spock: 4 + 6. Spock vaporizes rock: 5 + 6.
+    assert_answers(&day02, "E U\nA V", &["21"]);
+}
+
+#[test]
+fn relates_every_pair_of_shapes() {
+    let rules = Rules::from_toml(RPSLS).unwrap();
+    let shapes = rules.shapes().len();
+
+    for a in 0..shapes {
+        assert_eq!(rules.outcome(a, a), ExpectedResult::Draw);
+        for b in (0..shapes).filter(|&b| b != a) {
+            assert_ne!(rules.outcome(a, b), ExpectedResult::Draw);
+            assert_ne!(rules.outcome(a, b), rules.outcome(b, a));
+        }
+    }
+}
+
+#[test]
+fn rejects_inconsistent_rules() {
+    let standard = include_str!("../rules/rps.toml");
+
+    let error = |text: &str| {
+        let error = Rules::from_toml(text).unwrap_err();
+        (error.clone(), error.to_string())
+    };
+
+    let unrelated = standard.replace("beats = [\"paper\"]", "beats = []");
+    assert_eq!(error(&unrelated), (
+        RulesError::Unrelated("paper".to_owned(), "scissors".to_owned()),
+        "neither `paper` nor `scissors` beats the other".to_owned(),
+    ));
+
+    let mutual = standard.replace("beats = [\"rock\"]", "beats = [\"rock\", \"scissors\"]");
+    assert_eq!(error(&mutual), (
+        RulesError::BeatEachOther("paper".to_owned(), "scissors".to_owned()),
+        "`paper` and `scissors` beat each other".to_owned(),
+    ));
+
+    let unknown = standard.replace("beats = [\"rock\"]", "beats = [\"stone\"]");
+    assert_eq!(error(&unknown), (
+        RulesError::UnknownShape { winner: "paper".to_owned(), loser: "stone".to_owned() },
+        "`paper` beats `stone`, which isn't a shape".to_owned(),
+    ));
+
+    let itself = standard.replace("beats = [\"rock\"]", "beats = [\"paper\"]");
+    assert_eq!(error(&itself).1, "`paper` beats itself");
+
+    let twice = standard.replace("player = \"Y\"", "player = \"X\"");
+    assert_eq!(error(&twice), (
+        RulesError::Duplicate { what: "player symbol", value: "X".to_owned() },
+        "player symbol `X` is used twice".to_owned(),
+    ));
+}
+
+#[test]
+fn tells_syntax_errors_from_invalid_rules() {
+    let broken = "[outcomes]\nlose = { symbol = \"X\" score = 0 }";
+    let error = Rules::from_toml(broken).unwrap_err();
+    assert!(matches!(error, RulesError::Toml { position: Some((2, 23)), .. }), "{:?}", error);
+    assert!(error.to_string().starts_with("line 2, column 23: "), "{}", error);
+
+    assert!(matches!(Rules::from_toml("[outcomes]"), Err(RulesError::Toml { .. })));
+
+    let shapeless = "shapes = []\n[outcomes]\nlose = { symbol = \"X\", score = 0 }\n\
+                     draw = { symbol = \"Y\", score = 3 }\nwin = { symbol = \"Z\", score = 6 }";
+    assert_eq!(Rules::from_toml(shapeless), Err(RulesError::NoShapes));
+}
+
+#[test]
+fn reports_outcomes_no_shape_gets() {
+    // Paper beats both other shapes, so nothing wins against it.
+    let standard = include_str!("../rules/rps.toml");
+    let rules = standard.replace("beats = [\"rock\"]", "beats = [\"rock\", \"scissors\"]")
+        .replace("beats = [\"paper\"]", "beats = []");
+    let rules = Rules::from_toml(&rules).unwrap();
+
+    for report in [Report::Total, Report::Optimize, Report::Breakdown] {
+        let day02 = Day02 { rules: rules.clone(), report };
+
+        assert!(day02.run("A Z\nB Z", &[Part::One]).is_ok());
+        match day02.run("A Z\nB Z", &[Part::Two]) {
+            Err(Error::Input(message)) => assert_eq!(message, "no shape can win against `paper`"),
+            other => panic!("expected an input error, got {:?}", other),
+        }
+    }
+
+    // Every reading of the guide asks for a win against rock somewhere.
+    let optimize = Day02 { rules, report: Report::Optimize };
+    assert!(optimize.run("A Y", &[Part::Two]).is_ok());
+    assert!(optimize.run("B Y", &[Part::Two]).is_err());
+}
+
+#[test]
+fn loads_rules_from_files() {
+    let path = std::env::temp_dir().join(format!("day02-rules-test-{}.toml", std::process::id()));
+    let path_str = path.to_str().unwrap();
+    let mut day02 = Day02::default();
+
+    match day02.configure("rules", Some(path_str)) {
+        Err(e @ Error::Io(_)) => assert_eq!(e.exit_code(), 74),
+        other => panic!("expected an I/O error, got {:?}", other),
+    }
+
+    std::fs::write(&path, "[outcomes]\nlose = { symbol = \"X\" score = 0 }").unwrap();
+    let result = day02.configure("rules", Some(path_str));
+    std::fs::write(&path, include_str!("../rules/rpsls.toml")).unwrap();
+    let loaded = day02.configure("rules", Some(path_str));
+    let _ = std::fs::remove_file(&path);
+
+    match result {
+        Err(Error::Input(message)) => {
+            assert!(message.starts_with(&format!("invalid rules in {}: line 2, column 23: ", path_str)), "{}", message);
+        }
+        other => panic!("expected an input error, got {:?}", other),
+    }
+    assert!(loaded.is_ok());
+    assert_eq!(day02.rules.shapes().len(), 5);
+    assert!(matches!(day02.configure("rules", None), Err(Error::Usage(_))));
+}
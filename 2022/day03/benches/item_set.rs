@@ -0,0 +1,128 @@
+//! Compares the bitmask based `ItemSet` with the `String::contains` scans it
+//! replaced, on a generated input much larger than the puzzle's.
+//!
+//! Run with `cargo bench -p day03`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_core::bench::Stats;
+use day03::{char_to_priority, group_priority_sum, Grouping, parse_rucksacks, total_score};
+
+const RUCKSACKS: usize = 300_000;
+const ITERATIONS: usize = 10;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A xorshift generator, so the input is the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+/// Rucksacks shaped like the puzzle's: the compartments share exactly one
+/// item, and every group of three shares exactly one badge.
+fn generate() -> Vec<String> {
+    let mut rng = Rng(0x5eed);
+    let mut rucksacks = Vec::with_capacity(RUCKSACKS);
+
+    while rucksacks.len() < RUCKSACKS {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let (badge, others) = items.split_last().unwrap();
+
+        // Each elf of the group gets its own items besides the badge.
+        for own in others.chunks(others.len() / 3) {
+            let (first_pool, second_pool) = own.split_at(own.len() / 2);
+            let shared = first_pool[0];
+            let half = 8 + rng.below(17);
+
+            let mut first: Vec<u8> = (0..half).map(|_| first_pool[rng.below(first_pool.len())]).collect();
+            let mut second: Vec<u8> = (0..half).map(|_| second_pool[rng.below(second_pool.len())]).collect();
+            first[0] = *badge;
+            first[1] = shared;
+            second[0] = shared;
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+
+            rucksacks.push(first.into_iter().chain(second).map(char::from).collect());
+        }
+    }
+
+    rucksacks.truncate(RUCKSACKS - RUCKSACKS % 3);
+    rucksacks
+}
+
+fn process_line_contains(line: &str) -> String {
+    let mut result = String::new();
+    let (first, second) = line.split_at(line.len() / 2);
+
+    for ch in first.chars() {
+        if second.contains(ch) && !result.contains(ch) {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+fn total_score_contains(rucksacks: &[String]) -> u32 {
+    rucksacks.iter()
+        .map(|rucksack| process_line_contains(rucksack).chars().filter_map(char_to_priority).sum::<u32>())
+        .sum()
+}
+
+fn group_priority_sum_contains(rucksacks: &[String]) -> u32 {
+    rucksacks.chunks_exact(3)
+        .filter_map(|group| group[0].chars().find(|&ch| group[1].contains(ch) && group[2].contains(ch)))
+        .filter_map(char_to_priority)
+        .sum()
+}
+
+fn time(name: &str, mut f: impl FnMut() -> u32) -> Duration {
+    let samples = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    let stats = Stats::new(samples);
+
+    println!("{:<18} min {:>10.1?}  median {:>10.1?}  p95 {:>10.1?}", name, stats.min, stats.median, stats.p95);
+    stats.median
+}
+
+fn main() {
+    let lines = generate();
+    let input = lines.join("\n");
+    let rucksacks = parse_rucksacks(&input).unwrap();
+
+    assert!(lines.iter().all(|line| process_line_contains(line).len() == 1), "compartments share one item");
+
+    assert_eq!(total_score(&rucksacks), total_score_contains(&lines));
+    assert_eq!(group_priority_sum(&rucksacks, 3, Grouping::Chunks).unwrap(), group_priority_sum_contains(&lines));
+
+    println!("{} rucksacks, {} iterations", lines.len(), ITERATIONS);
+
+    let parse = time("set parse", || parse_rucksacks(&input).unwrap().len() as u32);
+    let set = time("set compartments", || total_score(&rucksacks));
+    let scan = time("scan compartments", || total_score_contains(&lines));
+    println!("{:.1}x faster, {:.1}x counting parsing", scan.as_secs_f64() / set.as_secs_f64(), scan.as_secs_f64() / (set + parse).as_secs_f64());
+
+    let set = time("set groups", || group_priority_sum(&rucksacks, 3, Grouping::Chunks).unwrap());
+    let scan = time("scan groups", || group_priority_sum_contains(&lines));
+    println!("{:.1}x faster", scan.as_secs_f64() / set.as_secs_f64());
+}
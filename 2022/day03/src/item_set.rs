@@ -0,0 +1,124 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::ops::{BitAnd, BitOr};
+
+/// A set of rucksack items, stored as a bitmask with bit `p` set for the
+/// item of priority `p`.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    /// Every item, priorities 1 to 52.
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// # Panics
+    ///
+    /// If `priority` isn't between 1 and 52.
+    pub fn insert(&mut self, priority: u32) {
+        assert!((1..=52).contains(&priority), "invalid priority {}", priority);
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(&self, priority: u32) -> bool {
+        priority < 64 && self.0 & (1 << priority) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// The priorities of the items, lowest first.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.iter().sum()
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl FromIterator<u32> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut set = ItemSet::new();
+        for priority in iter {
+            set.insert(priority);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = u32;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Debug for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The priorities in an [`ItemSet`], lowest first.
+#[derive(Clone, Debug)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let priority = self.0.trailing_zeros();
+        // Clears the lowest set bit.
+        self.0 &= self.0 - 1;
+
+        Some(priority)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
@@ -0,0 +1,205 @@
+mod item_set;
+mod rucksack;
+
+pub use item_set::ItemSet;
+pub use rucksack::{parse_rucksacks, parse_rucksacks_lenient, Rucksack, RucksackError};
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+
+/// The priority of item `c`, if it is one: items are ASCII letters.
+pub fn char_to_priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(u32::from(c) - u32::from('a') + 1),
+        'A'..='Z' => Some(u32::from(c) - u32::from('A') + 27),
+        _ => None,
+    }
+}
+
+/// The item of priority `priority`, the inverse of [`char_to_priority`].
+pub fn priority_to_char(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + priority as u8 - 1),
+        27..=52 => char::from(b'A' + priority as u8 - 27),
+        _ => panic!("invalid priority {}", priority),
+    }
+}
+
+/// The items in `items`, if they all are.
+pub fn items(items: &str) -> Option<ItemSet> {
+    items.chars().map(char_to_priority).collect()
+}
+
+pub fn total_score(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks.iter()
+        .map(|rucksack| rucksack.misplaced().priority_sum())
+        .sum()
+}
+
+/// How rucksacks are put into groups in the search for badges.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Grouping {
+    /// Consecutive groups that don't overlap, as the puzzle has it.
+    /// Rucksacks left over at the end don't make up a group.
+    #[default]
+    Chunks,
+    /// Every run of consecutive rucksacks, so the groups overlap.
+    Sliding,
+}
+
+/// Consecutive rucksacks searched for a badge together.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Group {
+    /// Line of the first rucksack, counting from 1.
+    pub line: usize,
+    /// Line of the last rucksack, counting from 1.
+    pub last_line: usize,
+    /// The items every rucksack of the group carries.
+    pub common: ItemSet,
+}
+
+impl Group {
+    /// The priority of the group's badge, which has to be the only item
+    /// all of its rucksacks carry.
+    pub fn badge(&self) -> Result<u32> {
+        match self.common.len() {
+            1 => Ok(self.common.iter().next().unwrap()),
+            0 => Err(Error::parse(self.line, format!("lines {}-{} have no item in common", self.line, self.last_line))),
+            _ => Err(Error::parse(self.line, format!(
+                "lines {}-{} have {} items in common: {}",
+                self.line,
+                self.last_line,
+                self.common.len(),
+                self.common.iter().map(priority_to_char).collect::<String>()
+            ))),
+        }
+    }
+}
+
+/// Splits the rucksacks into groups of `size`.
+///
+/// # Panics
+///
+/// If `size` is 0.
+pub fn groups(rucksacks: &[Rucksack], size: usize, grouping: Grouping) -> impl Iterator<Item = Group> + '_ {
+    let step = match grouping {
+        Grouping::Chunks => size,
+        Grouping::Sliding => 1,
+    };
+
+    rucksacks.windows(size)
+        .step_by(step)
+        .map(move |group| Group {
+            line: group[0].line,
+            last_line: group[size - 1].line,
+            common: group.iter().fold(ItemSet::ALL, |common, rucksack| common & rucksack.items()),
+        })
+}
+
+/// Sums up the badges of the groups, failing on the first group that
+/// doesn't have exactly one item in common.
+pub fn group_priority_sum(rucksacks: &[Rucksack], size: usize, grouping: Grouping) -> Result<u32> {
+    groups(rucksacks, size, grouping).map(|group| group.badge()).sum()
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Report {
+    /// The sum of the badges, as the puzzle asks.
+    #[default]
+    Sum,
+    /// The sum of the badges of the groups that have one, and the groups
+    /// with no item or several items in common.
+    Badges,
+}
+
+pub struct Day03 {
+    pub group_size: usize,
+    pub grouping: Grouping,
+    pub report: Report,
+    /// Skips invalid rucksacks with a warning instead of failing.
+    pub lenient: bool,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Self { group_size: 3, grouping: Grouping::default(), report: Report::default(), lenient: false }
+    }
+}
+
+impl Day03 {
+    fn report_badges(&self, rucksacks: &[Rucksack]) -> Answer {
+        let mut sum = 0;
+        let mut no_badge = Vec::new();
+        let mut several_badges = Vec::new();
+
+        for group in groups(rucksacks, self.group_size, self.grouping) {
+            let lines = format!("{}-{}", group.line, group.last_line);
+            match group.common.len() {
+                0 => no_badge.push(lines),
+                1 => sum += group.common.priority_sum(),
+                _ => several_badges.push(Answer::record([
+                    ("lines", Answer::from(lines)),
+                    ("items", group.common.iter().map(priority_to_char).collect::<String>().into()),
+                ])),
+            }
+        }
+
+        Answer::record([
+            ("sum", Answer::from(sum)),
+            ("no_badge", no_badge.into()),
+            ("several_badges", several_badges.into()),
+        ])
+    }
+}
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "group-size", value: Some("N"), help: "look for badges in groups of N elves in part 2" },
+        Flag { name: "sliding", value: None, help: "make a group of every N consecutive elves, overlapping" },
+        Flag { name: "badges", value: None, help: "list the groups without exactly one badge in part 2" },
+        Flag { name: "lenient", value: None, help: "skip invalid rucksacks with a warning instead of failing" },
+    ];
+
+    type Input = Vec<Rucksack>;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("group-size", Some(value)) => {
+                self.group_size = value.parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| Error::Usage(format!("invalid group size `{}`", value)))?;
+            }
+            ("sliding", None) => self.grouping = Grouping::Sliding,
+            ("badges", None) => self.report = Report::Badges,
+            ("lenient", None) => self.lenient = true,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        if !self.lenient {
+            return parse_rucksacks(input);
+        }
+
+        let (rucksacks, skipped) = parse_rucksacks_lenient(input);
+        for e in skipped {
+            eprintln!("skipping {}", e);
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(total_score(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        match self.report {
+            Report::Sum => Ok(group_priority_sum(input, self.group_size, self.grouping)?.into()),
+            Report::Badges => Ok(self.report_badges(input)),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Reading and checking the rucksacks of the input.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use aoc_core::{Error, Result};
+
+use crate::{char_to_priority, items, ItemSet};
+
+/// Why a line of the input isn't a rucksack.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RucksackError {
+    /// The line has no items at all.
+    Empty,
+    /// The items can't be split into two compartments of the same size.
+    OddLength(usize),
+    /// The item at `column`, counting from 1, is an ASCII character other
+    /// than a letter.
+    NotALetter { column: usize, item: char },
+    /// The item at `column`, counting from 1, isn't ASCII.
+    NotAscii { column: usize, item: char },
+}
+
+impl RucksackError {
+    fn column(&self) -> Option<usize> {
+        match self {
+            RucksackError::NotALetter { column, .. } | RucksackError::NotAscii { column, .. } => Some(*column),
+            RucksackError::Empty | RucksackError::OddLength(_) => None,
+        }
+    }
+
+    /// Locates the error on line `line` of the input.
+    pub fn at_line(&self, line: usize) -> Error {
+        match self.column() {
+            Some(column) => Error::parse_at(line, column, self.to_string()),
+            None => Error::parse(line, self.to_string()),
+        }
+    }
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RucksackError::Empty => write!(f, "the rucksack is empty"),
+            RucksackError::OddLength(len) => write!(f, "{} items can't be split into two compartments", len),
+            RucksackError::NotALetter { item, .. } => write!(f, "`{}` isn't an item, items are letters", item.escape_debug()),
+            RucksackError::NotAscii { item, .. } => write!(f, "`{}` isn't ASCII, items are letters", item.escape_debug()),
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// A rucksack, as the items in each of its two compartments.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Rucksack {
+    /// Line of the rucksack in the input, counting from 1.
+    pub line: usize,
+    pub first: ItemSet,
+    pub second: ItemSet,
+}
+
+impl Rucksack {
+    /// Reads the rucksack on line `line_no` of the input.
+    pub fn parse(line: &str, line_no: usize) -> Result<Self, RucksackError> {
+        for (idx, item) in line.chars().enumerate() {
+            if char_to_priority(item).is_none() {
+                let column = idx + 1;
+                return Err(if item.is_ascii() {
+                    RucksackError::NotALetter { column, item }
+                } else {
+                    RucksackError::NotAscii { column, item }
+                });
+            }
+        }
+
+        // Every item is an ASCII letter now, so bytes and items line up.
+        match line.len() {
+            0 => return Err(RucksackError::Empty),
+            len if len % 2 == 1 => return Err(RucksackError::OddLength(len)),
+            _ => {}
+        }
+        let (first, second) = line.split_at(line.len() / 2);
+
+        Ok(Rucksack {
+            line: line_no,
+            first: items(first).expect("checked the items"),
+            second: items(second).expect("checked the items"),
+        })
+    }
+
+    /// Every item in the rucksack.
+    pub fn items(&self) -> ItemSet {
+        self.first | self.second
+    }
+
+    /// The items packed into both compartments.
+    pub fn misplaced(&self) -> ItemSet {
+        self.first & self.second
+    }
+}
+
+/// Reads the rucksacks of `input`, failing on the first invalid line.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| Rucksack::parse(line, idx + 1).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+/// Reads the rucksacks of `input`, skipping invalid lines. Returns the
+/// valid rucksacks and why each of the other lines was skipped.
+pub fn parse_rucksacks_lenient(input: &str) -> (Vec<Rucksack>, Vec<Error>) {
+    let mut rucksacks = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        match Rucksack::parse(line, idx + 1) {
+            Ok(rucksack) => rucksacks.push(rucksack),
+            Err(e) => skipped.push(e.at_line(idx + 1)),
+        }
+    }
+
+    (rucksacks, skipped)
+}
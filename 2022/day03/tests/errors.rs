@@ -0,0 +1,61 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day03::{char_to_priority, items, parse_rucksacks_lenient, Day03, Rucksack, RucksackError};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+fn parse_error(input: &str) -> (usize, Option<usize>, String) {
+    match Day03::default().run(input, &Part::ALL) {
+        Err(Error::Parse { line, column, message }) => (line, column, message),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn only_letters_are_items() {
+    assert_eq!(char_to_priority('a'), Some(1));
+    assert_eq!(char_to_priority('Z'), Some(52));
+    assert_eq!(char_to_priority('0'), None);
+    assert_eq!(char_to_priority('['), None);
+    assert_eq!(char_to_priority('é'), None);
+    assert_eq!(items("ab-c"), None);
+}
+
+#[test]
+fn checks_rucksacks() {
+    assert_eq!(Rucksack::parse("", 1), Err(RucksackError::Empty));
+    assert_eq!(Rucksack::parse("abc", 1), Err(RucksackError::OddLength(3)));
+    assert_eq!(Rucksack::parse("ab1c", 1), Err(RucksackError::NotALetter { column: 3, item: '1' }));
+    assert_eq!(Rucksack::parse("ab c", 1), Err(RucksackError::NotALetter { column: 3, item: ' ' }));
+    // Two bytes, so the length is even, but still one item.
+    assert_eq!(Rucksack::parse("éa", 1), Err(RucksackError::NotAscii { column: 1, item: 'é' }));
+    assert_eq!(Rucksack::parse("aBéa", 1), Err(RucksackError::NotAscii { column: 3, item: 'é' }));
+}
+
+#[test]
+fn reports_bad_lines() {
+    assert_eq!(parse_error("abab\nabc\n"), (2, None, "3 items can't be split into two compartments".to_owned()));
+    assert_eq!(parse_error("abab\n\nabab"), (2, None, "the rucksack is empty".to_owned()));
+    assert_eq!(parse_error("abab\nab\ta\n"), (2, Some(3), "`\\t` isn't an item, items are letters".to_owned()));
+    assert_eq!(parse_error("abab\nabab\naböb"), (3, Some(3), "`ö` isn't ASCII, items are letters".to_owned()));
+}
+
+#[test]
+fn skips_bad_lines_when_lenient() {
+    let input = EXAMPLE.replacen('\n', "\nabc\n", 1).replacen("ttgJ", "ttg7", 1);
+    let (rucksacks, skipped) = parse_rucksacks_lenient(&input);
+
+    assert_eq!(rucksacks.iter().map(|r| r.line).collect::<Vec<_>>(), [1, 3, 4, 5, 7]);
+    assert_eq!(
+        skipped.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        ["line 2: 3 items can't be split into two compartments", "line 6, column 4: `7` isn't an item, items are letters"]
+    );
+}
+
+#[test]
+fn solves_what_is_left_when_lenient() {
+    let day03 = Day03 { lenient: true, ..Day03::default() };
+    let input = EXAMPLE.replacen('\n', "\nabc\n", 1);
+
+    assert_answers(&day03, &input, &["157", "70"]);
+}
@@ -0,0 +1,9 @@
+use aoc_core::testing::assert_answers;
+use day03::Day03;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day03::default(), EXAMPLE, &["157", "70"]);
+}
@@ -0,0 +1,61 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day03::{groups, parse_rucksacks, Day03, Grouping, Report};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn splits_into_chunks_or_windows() {
+    let rucksacks = parse_rucksacks(EXAMPLE).unwrap();
+
+    let chunks: Vec<_> = groups(&rucksacks, 2, Grouping::Chunks).map(|g| (g.line, g.last_line)).collect();
+    assert_eq!(chunks, [(1, 2), (3, 4), (5, 6)]);
+
+    let chunks: Vec<_> = groups(&rucksacks, 4, Grouping::Chunks).map(|g| (g.line, g.last_line)).collect();
+    assert_eq!(chunks, [(1, 4)]);
+
+    let windows: Vec<_> = groups(&rucksacks, 3, Grouping::Sliding).map(|g| (g.line, g.last_line)).collect();
+    assert_eq!(windows, [(1, 3), (2, 4), (3, 5), (4, 6)]);
+
+    assert_eq!(groups(&rucksacks, 7, Grouping::Sliding).count(), 0);
+}
+
+#[test]
+fn sums_up_sliding_badges() {
+    let day03 = Day03 { grouping: Grouping::Sliding, ..Day03::default() };
+
+    // r + q + T + Z
+    assert_answers(&day03, EXAMPLE, &["157", "133"]);
+}
+
+#[test]
+fn fails_on_groups_without_exactly_one_badge() {
+    let day03 = Day03 { group_size: 2, ..Day03::default() };
+
+    match day03.run(EXAMPLE, &[Part::Two]) {
+        Err(Error::Parse { line, message, .. }) => {
+            assert_eq!((line, message.as_str()), (1, "lines 1-2 have 5 items in common: frsFM"));
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+
+    let day03 = Day03 { group_size: 6, ..Day03::default() };
+
+    match day03.run(EXAMPLE, &[Part::Two]) {
+        Err(Error::Parse { line, message, .. }) => {
+            assert_eq!((line, message.as_str()), (1, "lines 1-6 have no item in common"));
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn reports_groups_without_exactly_one_badge() {
+    let day03 = Day03 { group_size: 2, report: Report::Badges, ..Day03::default() };
+    let input = "aAbB\nabcd\nxyXY\nzZzZ\npqpq\npzpz\n";
+
+    assert_eq!(
+        day03.run(input, &[Part::Two]).unwrap()[0].answer.to_string(),
+        "{sum: 16, no_badge: [3-4], several_badges: [{lines: 1-2, items: ab}]}"
+    );
+}
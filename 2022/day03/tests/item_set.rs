@@ -0,0 +1,33 @@
+use day03::{items, ItemSet, Rucksack};
+
+#[test]
+fn set_operations() {
+    let a: ItemSet = [1, 5, 52].into_iter().collect();
+    let b: ItemSet = [5, 27].into_iter().collect();
+
+    assert_eq!((a | b).iter().collect::<Vec<_>>(), [1, 5, 27, 52]);
+    assert_eq!((a & b).iter().collect::<Vec<_>>(), [5]);
+    assert_eq!(a.union(b), a | b);
+    assert_eq!(a.intersection(b), a & b);
+    assert_eq!(a.len(), 3);
+    assert!(a.contains(52) && !a.contains(27) && !a.contains(0) && !a.contains(99));
+    assert!((a & ItemSet::EMPTY).is_empty());
+    assert_eq!(ItemSet::ALL.len(), 52);
+    assert_eq!(ItemSet::ALL.priority_sum(), (1..=52).sum());
+}
+
+#[test]
+fn reads_items() {
+    assert_eq!(items("aAzZa").unwrap().iter().collect::<Vec<_>>(), [1, 26, 27, 52]);
+
+    let rucksack = Rucksack::parse("vJrwpWtwJgWrhcsFMMfFFhFp", 1).unwrap();
+    assert_eq!(rucksack.first, items("vJrwpWtwJgWr").unwrap());
+    assert_eq!(rucksack.misplaced().iter().collect::<Vec<_>>(), [16]);
+    assert_eq!(rucksack.items(), items("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid priority 53")]
+fn rejects_invalid_priorities() {
+    ItemSet::new().insert(53);
+}
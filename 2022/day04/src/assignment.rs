@@ -0,0 +1,81 @@
+//! Reading the section assignments of the pairs of elves.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::num::IntErrorKind;
+
+use aoc_core::{Error, Result};
+use aoc_interval::ClosedInterval;
+
+/// The sections assigned to an elf, both ends included.
+pub type Range = ClosedInterval<u64>;
+
+/// Why a line of the input isn't a pair of assignments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssignmentError {
+    /// The line doesn't have exactly two ranges separated by a comma.
+    Ranges(usize),
+    /// The range has no dash between its bounds.
+    MissingDash(String),
+    /// The range is missing a bound on one side of its dash.
+    MissingBound(String),
+    /// A bound isn't a section number.
+    NotANumber(String),
+    /// A bound is too large for a section number.
+    TooLarge(String),
+    /// The range ends before it starts.
+    Inverted { start: u64, end: u64 },
+}
+
+impl Display for AssignmentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentError::Ranges(found) => write!(f, "expected 2 ranges separated by `,`, found {}", found),
+            AssignmentError::MissingDash(range) => write!(f, "`{}` isn't a range, expected `start-end`", range),
+            AssignmentError::MissingBound(range) => write!(f, "`{}` is missing a bound", range),
+            AssignmentError::NotANumber(bound) => write!(f, "`{}` isn't a section number", bound),
+            AssignmentError::TooLarge(bound) => write!(f, "section `{}` doesn't fit in 64 bits", bound),
+            AssignmentError::Inverted { start, end } => write!(f, "`{}-{}` ends before it starts", start, end),
+        }
+    }
+}
+
+impl std::error::Error for AssignmentError {}
+
+fn parse_bound(bound: &str) -> Result<u64, AssignmentError> {
+    bound.parse().map_err(|e: std::num::ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow => AssignmentError::TooLarge(bound.to_owned()),
+        _ => AssignmentError::NotANumber(bound.to_owned()),
+    })
+}
+
+/// Reads a range written as `start-end`. Ranges ending before they start
+/// are an error, unless `normalize` is set, which swaps their bounds.
+pub fn parse_range(s: &str, normalize: bool) -> Result<Range, AssignmentError> {
+    let (start, end) = s.split_once('-').ok_or_else(|| AssignmentError::MissingDash(s.to_owned()))?;
+    if start.is_empty() || end.is_empty() {
+        return Err(AssignmentError::MissingBound(s.to_owned()));
+    }
+
+    let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+    match (start <= end, normalize) {
+        (true, _) => Ok(Range::new(start, end)),
+        (false, true) => Ok(Range::new(end, start)),
+        (false, false) => Err(AssignmentError::Inverted { start, end }),
+    }
+}
+
+/// Reads the two ranges of a line, see [`parse_range`].
+pub fn parse_line(line: &str, normalize: bool) -> Result<(Range, Range), AssignmentError> {
+    match line.split(',').collect::<Vec<_>>()[..] {
+        [first, second] => Ok((parse_range(first, normalize)?, parse_range(second, normalize)?)),
+        ref ranges => Err(AssignmentError::Ranges(ranges.len())),
+    }
+}
+
+pub fn parse_pairs(input: &str, normalize: bool) -> Result<Vec<(Range, Range)>> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line, normalize).map_err(|e| Error::parse(idx + 1, e.to_string())))
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Looking at every assignment at once instead of pair by pair.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use aoc_interval::{Closed, IntervalSet};
+
+use crate::Range;
+
+/// Sections as a set of ranges.
+pub type Sections = IntervalSet<u64, Closed>;
+
+/// Which elf an assignment belongs to.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ElfId {
+    /// Line of the elf's pair, counting from 1.
+    pub line: usize,
+    /// 1 for the first elf of the pair, 2 for the second one.
+    pub position: usize,
+}
+
+impl Display for ElfId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.position)
+    }
+}
+
+/// Every assignment of the pairs, with the elf it belongs to.
+pub fn assignments(pairs: &[(Range, Range)]) -> Vec<(ElfId, Range)> {
+    pairs.iter()
+        .enumerate()
+        .flat_map(|(idx, &(first, second))| [
+            (ElfId { line: idx + 1, position: 1 }, first),
+            (ElfId { line: idx + 1, position: 2 }, second),
+        ])
+        .collect()
+}
+
+/// The sections from the first assigned one to the last that no elf is
+/// assigned to.
+pub fn uncovered(assignments: &[(ElfId, Range)]) -> Sections {
+    let covered: Sections = assignments.iter().map(|&(_, range)| range).collect();
+
+    match (covered.intervals().first(), covered.intervals().last()) {
+        (Some(first), Some(last)) => covered.gaps(Range::new(first.start(), last.end())),
+        _ => Sections::new(),
+    }
+}
+
+/// The most elves any section is assigned to, and the sections assigned to
+/// that many elves.
+pub fn busiest(assignments: &[(ElfId, Range)]) -> (usize, Sections) {
+    // Each assignment counts from its first section up to right after its
+    // last one, which is past `u64::MAX` for assignments ending there.
+    let mut events: Vec<(u128, isize)> = assignments.iter()
+        .filter_map(|(_, range)| Some([(u128::from(range.first()?), 1), (u128::from(range.last()?) + 1, -1)]))
+        .flatten()
+        .collect();
+    events.sort_unstable();
+
+    let mut segments = Vec::new();
+    let mut depth = 0;
+    for (idx, &(section, delta)) in events.iter().enumerate() {
+        depth += delta;
+        // Segments end where the next event happens, so only the last of
+        // the events at a section starts one.
+        if let Some(&(next, _)) = events.get(idx + 1).filter(|&&(next, _)| next > section) {
+            segments.push((depth as usize, section, next - 1));
+        }
+    }
+
+    let most = segments.iter().map(|&(depth, _, _)| depth).max().unwrap_or(0);
+    let sections = segments.into_iter()
+        .filter(|&(depth, _, _)| depth == most && most > 0)
+        .map(|(_, first, last)| Range::new(first as u64, last as u64))
+        .collect();
+
+    (most, sections)
+}
+
+/// The elves from different lines whose assignments overlap, in order.
+///
+/// Sweeps over the assignments by first section, keeping those that are
+/// still going on in a heap by last section, so this takes O(n log n) for
+/// n assignments, plus the overlaps found.
+pub fn overlapping_elves(assignments: &[(ElfId, Range)]) -> Vec<(ElfId, ElfId)> {
+    let mut sorted: Vec<(u64, u64, ElfId)> = assignments.iter()
+        .filter_map(|&(elf, range)| Some((range.first()?, range.last()?, elf)))
+        .collect();
+    sorted.sort_unstable();
+
+    let mut overlaps = Vec::new();
+    let mut active: BinaryHeap<Reverse<(u64, ElfId)>> = BinaryHeap::new();
+    for (first, last, elf) in sorted {
+        while active.peek().is_some_and(|Reverse((end, _))| *end < first) {
+            active.pop();
+        }
+
+        overlaps.extend(active.iter()
+            .filter(|Reverse((_, other))| other.line != elf.line)
+            .map(|&Reverse((_, other))| (other.min(elf), other.max(elf))));
+        active.push(Reverse((last, elf)));
+    }
+
+    overlaps.sort_unstable();
+    overlaps
+}
@@ -0,0 +1,99 @@
+mod assignment;
+mod coverage;
+
+pub use assignment::{parse_line, parse_pairs, parse_range, AssignmentError, Range};
+pub use coverage::{assignments, busiest, overlapping_elves, uncovered, ElfId, Sections};
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+
+pub fn fully_contained(first: &Range, second: &Range) -> bool {
+    first.contains_interval(second) || second.contains_interval(first)
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Report {
+    /// The pairs that overlap, as the puzzle asks.
+    #[default]
+    Pairs,
+    /// Every assignment of every pair at once: the sections nobody is
+    /// assigned to, those assigned to the most elves, and the elves of
+    /// different pairs whose assignments overlap.
+    Coverage,
+}
+
+#[derive(Default)]
+pub struct Day04 {
+    pub report: Report,
+    /// Swaps the bounds of ranges that end before they start instead of
+    /// failing on them.
+    pub normalize: bool,
+}
+
+fn sections_answer(sections: &Sections) -> Answer {
+    sections.iter()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+impl Day04 {
+    fn report_coverage(pairs: &[(Range, Range)]) -> Answer {
+        let assignments = assignments(pairs);
+        let (most_elves, busiest) = busiest(&assignments);
+        let overlaps: Vec<Answer> = overlapping_elves(&assignments).into_iter()
+            .map(|(a, b)| format!("{} {}", a, b).into())
+            .collect();
+
+        Answer::record([
+            ("uncovered", sections_answer(&uncovered(&assignments))),
+            ("most_elves", most_elves.into()),
+            ("busiest", sections_answer(&busiest)),
+            ("overlaps", overlaps.into()),
+        ])
+    }
+}
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "coverage", value: None, help: "report coverage and overlaps of all assignments in part 2" },
+        Flag { name: "normalize", value: None, help: "swap the bounds of ranges that end before they start" },
+    ];
+
+    type Input = Vec<(Range, Range)>;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("coverage", None) => self.report = Report::Coverage,
+            ("normalize", None) => self.normalize = true,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_pairs(input, self.normalize)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let count = input.iter()
+            .filter(|(first, second)| fully_contained(first, second))
+            .count();
+
+        Ok(count.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        if self.report == Report::Coverage {
+            return Ok(Self::report_coverage(input));
+        }
+
+        let count = input.iter()
+            .filter(|(first, second)| first.overlaps(second))
+            .count();
+
+        Ok(count.into())
+    }
+}
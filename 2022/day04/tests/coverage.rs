@@ -0,0 +1,89 @@
+use aoc_core::{Part, Runnable};
+use day04::{assignments, busiest, overlapping_elves, parse_line, uncovered, Day04, ElfId, Range, Report};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+fn pairs(input: &str) -> Vec<(Range, Range)> {
+    input.lines().map(|line| parse_line(line, false).unwrap()).collect()
+}
+
+fn ranges(sections: &day04::Sections) -> Vec<(u64, u64)> {
+    sections.iter().map(|range| (range.start(), range.end())).collect()
+}
+
+#[test]
+fn finds_uncovered_and_busiest_sections() {
+    let assignments = assignments(&pairs("2-4,20-22\n3-5,10-12\n11-11,4-4\n"));
+
+    assert_eq!(ranges(&uncovered(&assignments)), [(6, 9), (13, 19)]);
+
+    let (most, sections) = busiest(&assignments);
+    assert_eq!(most, 3);
+    assert_eq!(ranges(&sections), [(4, 4)]);
+}
+
+#[test]
+fn counts_sections_at_the_end_of_the_range() {
+    let max = u64::MAX;
+    let input = format!("{}-{},{}-{}\n", max - 2, max, max - 1, max);
+    let (most, sections) = busiest(&assignments(&pairs(&input)));
+
+    assert_eq!(most, 2);
+    assert_eq!(ranges(&sections), [(max - 1, max)]);
+}
+
+#[test]
+fn skips_overlaps_within_a_pair() {
+    let overlaps = overlapping_elves(&assignments(&pairs("2-8,3-7\n9-9,1-2\n")));
+
+    assert_eq!(overlaps, [(ElfId { line: 1, position: 1 }, ElfId { line: 2, position: 2 })]);
+}
+
+#[test]
+fn sweeps_like_comparing_every_pair() {
+    // Assignments from a small linear congruential generator.
+    let mut state = 7u64;
+    let mut next = |n: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % n
+    };
+    let input: String = (0..200)
+        .map(|_| {
+            let (a, b) = (next(500), next(500));
+            let (c, d) = (next(500), next(500));
+            format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d))
+        })
+        .collect();
+    let assignments = assignments(&pairs(&input));
+
+    let mut expected = Vec::new();
+    for (i, &(a, x)) in assignments.iter().enumerate() {
+        for &(b, y) in &assignments[i + 1..] {
+            if a.line != b.line && x.overlaps(&y) {
+                expected.push((a.min(b), a.max(b)));
+            }
+        }
+    }
+    expected.sort_unstable();
+    assert_eq!(overlapping_elves(&assignments), expected);
+
+    let depth = |section: u64| assignments.iter().filter(|(_, range)| range.contains(section)).count();
+    let (most, sections) = busiest(&assignments);
+    assert_eq!((0..500).map(depth).max(), Some(most));
+    assert!((0..500).all(|section| sections.contains(section) == (depth(section) == most)));
+}
+
+#[test]
+fn reports_coverage() {
+    let day04 = Day04 { report: Report::Coverage, ..Day04::default() };
+    let records = day04.run("2-4,6-8\n3-4,10-10\n", &[Part::Two]).unwrap();
+
+    assert_eq!(
+        records[0].answer.to_string(),
+        "{uncovered: [5-5, 9-9], most_elves: 2, busiest: [3-4], overlaps: [1:1 2:1]}"
+    );
+
+    let records = day04.run(EXAMPLE, &Part::ALL).unwrap();
+    assert_eq!(records[0].answer.to_string(), "2");
+    assert!(records[1].answer.to_string().starts_with("{uncovered: [], most_elves: 8, busiest: [6-6], overlaps: [1:1 2:1,"));
+}
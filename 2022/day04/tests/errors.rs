@@ -0,0 +1,53 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day04::{parse_line, parse_range, AssignmentError, Day04, Range};
+
+fn parse_error(input: &str) -> (usize, String) {
+    match Day04::default().run(input, &Part::ALL) {
+        Err(Error::Parse { line, message, .. }) => (line, message),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn reads_ranges() {
+    assert_eq!(parse_range("2-4", false), Ok(Range::new(2, 4)));
+    assert_eq!(parse_range("6-6", false), Ok(Range::new(6, 6)));
+    assert_eq!(parse_range("24", false), Err(AssignmentError::MissingDash("24".to_owned())));
+    assert_eq!(parse_range("-4", false), Err(AssignmentError::MissingBound("-4".to_owned())));
+    assert_eq!(parse_range("2-", false), Err(AssignmentError::MissingBound("2-".to_owned())));
+    assert_eq!(parse_range("2-x", false), Err(AssignmentError::NotANumber("x".to_owned())));
+    assert_eq!(parse_range("2-3-4", false), Err(AssignmentError::NotANumber("3-4".to_owned())));
+    assert_eq!(
+        parse_range("1-18446744073709551616", false),
+        Err(AssignmentError::TooLarge("18446744073709551616".to_owned()))
+    );
+}
+
+#[test]
+fn rejects_inverted_ranges_unless_normalizing() {
+    assert_eq!(parse_range("7-3", false), Err(AssignmentError::Inverted { start: 7, end: 3 }));
+    assert_eq!(parse_range("7-3", true), Ok(Range::new(3, 7)));
+    assert_eq!(parse_line("2-4,8-6", true), Ok((Range::new(2, 4), Range::new(6, 8))));
+}
+
+#[test]
+fn reads_two_ranges_per_line() {
+    assert_eq!(parse_line("2-4", false), Err(AssignmentError::Ranges(1)));
+    assert_eq!(parse_line("2-4,6-8,1-1", false), Err(AssignmentError::Ranges(3)));
+}
+
+#[test]
+fn reports_bad_lines() {
+    assert_eq!(parse_error("2-4,6-8\n2-3;4-5\n"), (2, "expected 2 ranges separated by `,`, found 1".to_owned()));
+    assert_eq!(parse_error("2-4,6-8\n2-4,68\n"), (2, "`68` isn't a range, expected `start-end`".to_owned()));
+    assert_eq!(parse_error("2-4,6-8\n2-3,4-5\n5-7,9-7\n"), (3, "`9-7` ends before it starts".to_owned()));
+    assert_eq!(parse_error("2-4,6-8\n2-3,4 -5\n"), (2, "`4 ` isn't a section number".to_owned()));
+}
+
+#[test]
+fn solves_normalized_ranges() {
+    let day04 = Day04 { normalize: true, ..Day04::default() };
+
+    assert_answers(&day04, "4-2,8-6\n3-2,5-4\n5-7,9-7\n8-2,3-7\n6-6,6-4\n6-2,8-4\n", &["2", "4"]);
+}
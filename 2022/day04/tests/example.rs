@@ -0,0 +1,9 @@
+use aoc_core::testing::assert_answers;
+use day04::Day04;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day04::default(), EXAMPLE, &["2", "4"]);
+}
@@ -0,0 +1,121 @@
+//! The crane models that can carry out the rearrangement procedure.
+
+use aoc_core::{Error, Result};
+
+use crate::{Command, Crate};
+
+/// A model of crane, which decides how the crates of a command get from
+/// one stack to the other.
+pub trait Crane {
+    /// Carries out `command`, moving its crates between `stacks`.
+    fn execute(&self, stacks: &mut [Vec<Crate>], command: &Command) -> Result<()>;
+}
+
+/// Takes the top `count` crates off the stack `command` moves crates from,
+/// bottom crate first.
+pub fn lift(stacks: &mut [Vec<Crate>], command: &Command, count: usize) -> Result<Vec<Crate>> {
+    let stack = &mut stacks[command.from() - 1];
+    let rest = stack.len().checked_sub(count).ok_or_else(|| Error::Input(format!(
+        "can't move {} crates from stack {}, it runs out of crates",
+        command.count(),
+        command.from()
+    )))?;
+
+    Ok(stack.split_off(rest))
+}
+
+/// Sets `crates` down on the stack `command` moves crates to, bottom crate
+/// first.
+pub fn place(stacks: &mut [Vec<Crate>], command: &Command, crates: Vec<Crate>) {
+    stacks[command.to() - 1].extend(crates);
+}
+
+/// Moves as many crates at once as it can carry, at most `capacity`,
+/// keeping their order, which takes several trips for larger commands.
+fn execute_in_trips(stacks: &mut [Vec<Crate>], command: &Command, capacity: u64) -> Result<()> {
+    let mut left = command.count();
+
+    while left > 0 {
+        let trip = left.min(capacity);
+        // A trip larger than memory can't find enough crates anyway.
+        let crates = lift(stacks, command, usize::try_from(trip).unwrap_or(usize::MAX))?;
+        place(stacks, command, crates);
+        left -= trip;
+    }
+
+    Ok(())
+}
+
+/// Moves one crate at a time, so the moved crates end up in reverse order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn execute(&self, stacks: &mut [Vec<Crate>], command: &Command) -> Result<()> {
+        execute_in_trips(stacks, command, 1)
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn execute(&self, stacks: &mut [Vec<Crate>], command: &Command) -> Result<()> {
+        execute_in_trips(stacks, command, u64::MAX)
+    }
+}
+
+/// Like the [`CrateMover9001`], but lifts at most `capacity` crates at a
+/// time, starting with the top ones.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LimitedCrane {
+    pub capacity: u64,
+}
+
+impl Crane for LimitedCrane {
+    fn execute(&self, stacks: &mut [Vec<Crate>], command: &Command) -> Result<()> {
+        execute_in_trips(stacks, command, self.capacity.max(1))
+    }
+}
+
+/// Lifts all the crates at once and rotates the pile on the way, so the
+/// bottom crate of the pile ends up on top of the others.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RotatingCrane;
+
+impl Crane for RotatingCrane {
+    fn execute(&self, stacks: &mut [Vec<Crate>], command: &Command) -> Result<()> {
+        let count = usize::try_from(command.count()).unwrap_or(usize::MAX);
+        let mut crates = lift(stacks, command, count)?;
+        if !crates.is_empty() {
+            crates.rotate_left(1);
+        }
+        place(stacks, command, crates);
+
+        Ok(())
+    }
+}
+
+/// Carries out every command with `crane`.
+pub fn replay(crane: &dyn Crane, stacks: &mut [Vec<Crate>], commands: &[Command]) -> Result<()> {
+    for command in commands {
+        crane.execute(stacks, command)?;
+    }
+
+    Ok(())
+}
+
+/// The crane called `name` on the command line: `9000`, `9001`,
+/// `limited:N` or `rotating`.
+pub fn crane_by_name(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" => Some(Box::new(CrateMover9000)),
+        "9001" => Some(Box::new(CrateMover9001)),
+        "rotating" => Some(Box::new(RotatingCrane)),
+        _ => {
+            let capacity = name.strip_prefix("limited:")?.parse().ok().filter(|&n| n > 0)?;
+            Some(Box::new(LimitedCrane { capacity }))
+        }
+    }
+}
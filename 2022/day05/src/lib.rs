@@ -0,0 +1,211 @@
+use std::iter::Enumerate;
+use std::str::{FromStr, Lines};
+
+mod crane;
+
+pub use crane::{crane_by_name, lift, place, replay, Crane, CrateMover9000, CrateMover9001, LimitedCrane, RotatingCrane};
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+use regex::{Match, Regex};
+
+/// The ID of a crate, as written between its brackets in the drawing.
+pub type Crate = String;
+
+/// Reads the drawing of the stacks, up to the empty line after it, into
+/// stacks listed bottom crate first.
+///
+/// The last line of the drawing labels the stacks `1 2 3 ...`, and every
+/// crate above belongs to the stack whose label is under it, so stacks can
+/// be any number and crates any width, and lines can stop after their last
+/// crate.
+pub fn parse_stacks(lines: &mut Enumerate<Lines>) -> Result<Vec<Vec<Crate>>> {
+    let drawing: Vec<(usize, &str)> = lines.by_ref()
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| (idx + 1, line))
+        .collect();
+    let ((label_line, label_row), rows) = drawing.split_last()
+        .ok_or_else(|| Error::parse(1, "expected a drawing of the stacks"))?;
+
+    let tokens = Regex::new(r"\S+").unwrap();
+
+    let mut labels = Vec::new();
+    for m in tokens.find_iter(label_row) {
+        if m.as_str().parse() != Ok(labels.len() + 1) {
+            return Err(Error::parse_at(*label_line, m.start() + 1, format!(
+                "expected stack label {}, found `{}`",
+                labels.len() + 1,
+                m.as_str()
+            )));
+        }
+        labels.push(m.range());
+    }
+
+    let mut stacks: Vec<Vec<Crate>> = vec![Vec::new(); labels.len()];
+    for &(line, row) in rows {
+        let mut row_crates = vec![None; labels.len()];
+
+        for m in tokens.find_iter(row) {
+            let column = m.start() + 1;
+            let id = m.as_str()
+                .strip_prefix('[')
+                .and_then(|token| token.strip_suffix(']'))
+                .filter(|id| !id.is_empty() && !id.contains(['[', ']']))
+                .ok_or_else(|| Error::parse_at(line, column, format!("expected a crate like `[A]`, found `{}`", m.as_str())))?;
+
+            let mut below = labels.iter().enumerate().filter(|(_, label)| label.start < m.end() && m.start() < label.end);
+            let stack = match (below.next(), below.next()) {
+                (Some((stack, _)), None) => stack,
+                _ => return Err(Error::parse_at(line, column, format!("crate `{}` isn't above a single stack label", m.as_str()))),
+            };
+            if row_crates[stack].replace(id.to_owned()).is_some() {
+                return Err(Error::parse_at(line, column, format!("stack {} already has a crate on this line", stack + 1)));
+            }
+        }
+
+        for (stack, (crates, row_crate)) in stacks.iter_mut().zip(row_crates).enumerate() {
+            match row_crate {
+                Some(id) => crates.push(id),
+                None if !crates.is_empty() => {
+                    return Err(Error::parse(line, format!("stack {} has no crate here to hold up those above", stack + 1)));
+                }
+                None => {}
+            }
+        }
+    }
+
+    for s in &mut stacks {
+        s.reverse();
+    }
+
+    Ok(stacks)
+}
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct Command {
+    count: u64,
+    from: usize,
+    to: usize,
+}
+
+impl Command {
+    /// How many crates to move.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The stack to move crates from, counting from 1.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// The stack to move crates to, counting from 1.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+}
+
+fn parse_number<T: FromStr>(line: usize, m: Match) -> Result<T> {
+    m.as_str().parse()
+        .map_err(|_| Error::parse_at(line, m.start() + 1, format!("`{}` is too large", m.as_str())))
+}
+
+pub fn parse_commands(lines: &mut Enumerate<Lines>, stacks_num: usize) -> Result<Vec<Command>> {
+    let mut commands = Vec::new();
+    let rx = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+
+    for (line_idx, input) in lines {
+        if input.is_empty() {
+            continue;
+        }
+
+        let line = line_idx + 1;
+        let caps = rx.captures(input)
+            .ok_or_else(|| Error::parse(line, format!("expected `move N from X to Y`, found `{}`", input)))?;
+        let count = parse_number(line, caps.get(1).unwrap())?;
+        let from = parse_number(line, caps.get(2).unwrap())?;
+        let to = parse_number(line, caps.get(3).unwrap())?;
+
+        for stack in [from, to] {
+            if stack == 0 || stack > stacks_num {
+                return Err(Error::parse(line, format!("there is no stack {}", stack)));
+            }
+        }
+
+        commands.push(Command { count, from, to });
+    }
+
+    Ok(commands)
+}
+
+pub fn get_solution(stacks: &[Vec<Crate>]) -> Result<String> {
+    let mut res = String::new();
+
+    for (idx, s) in stacks.iter().enumerate() {
+        let top = s.last()
+            .ok_or_else(|| Error::NoSolution(format!("stack {} ends up empty", idx + 1)))?;
+        res.push_str(top);
+    }
+
+    Ok(res)
+}
+
+pub struct Puzzle {
+    pub stacks: Vec<Vec<Crate>>,
+    pub commands: Vec<Command>,
+}
+
+pub fn parse_puzzle(input: &str) -> Result<Puzzle> {
+    let mut lines = input.lines().enumerate();
+    let stacks = parse_stacks(&mut lines)?;
+    let commands = parse_commands(&mut lines, stacks.len())?;
+
+    Ok(Puzzle { stacks, commands })
+}
+
+pub struct Day05 {
+    /// The crane replaying the procedure in part 2.
+    pub crane: Box<dyn Crane>,
+}
+
+impl Default for Day05 {
+    fn default() -> Self {
+        Self { crane: Box::new(CrateMover9001) }
+    }
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "crane", value: Some("MODEL"), help: "replay part 2 with crane 9000, 9001, limited:N or rotating" },
+    ];
+
+    type Input = Puzzle;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("crane", Some(value)) => {
+                self.crane = crane_by_name(value).ok_or_else(|| Error::Usage(format!("unknown crane `{}`", value)))?;
+            }
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut stacks = input.stacks.clone();
+        replay(&CrateMover9000, &mut stacks, &input.commands)?;
+        Ok(get_solution(&stacks)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut stacks = input.stacks.clone();
+        replay(self.crane.as_ref(), &mut stacks, &input.commands)?;
+        Ok(get_solution(&stacks)?.into())
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Runnable};
+use day05::{crane_by_name, parse_puzzle, replay, Crane, CrateMover9000, CrateMover9001, Day05, LimitedCrane, RotatingCrane};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+const TOWER: &str = "[D]\n[C]\n[B]\n[A]\n 1   2\n\n";
+
+fn replay_with(crane: &dyn Crane, input: &str) -> Vec<Vec<String>> {
+    let puzzle = parse_puzzle(input).unwrap();
+    let mut stacks = puzzle.stacks.clone();
+    replay(crane, &mut stacks, &puzzle.commands).unwrap();
+    stacks
+}
+
+#[test]
+fn cranes_move_crates_their_own_way() {
+    let input = format!("{}move 4 from 1 to 2\n", TOWER);
+
+    assert_eq!(replay_with(&CrateMover9000, &input)[1], ["D", "C", "B", "A"]);
+    assert_eq!(replay_with(&CrateMover9001, &input)[1], ["A", "B", "C", "D"]);
+    assert_eq!(replay_with(&LimitedCrane { capacity: 2 }, &input)[1], ["C", "D", "A", "B"]);
+    assert_eq!(replay_with(&LimitedCrane { capacity: 4 }, &input)[1], ["A", "B", "C", "D"]);
+    assert_eq!(replay_with(&RotatingCrane, &input)[1], ["B", "C", "D", "A"]);
+}
+
+#[test]
+fn moving_onto_the_same_stack_changes_nothing() {
+    let input = format!("{}move 3 from 1 to 1\n", TOWER);
+
+    for crane in ["9000", "9001", "limited:2"] {
+        assert_eq!(replay_with(crane_by_name(crane).unwrap().as_ref(), &input)[0], ["A", "B", "C", "D"]);
+    }
+}
+
+#[test]
+fn fails_when_crates_run_out() {
+    let puzzle = parse_puzzle(&format!("{}move 5 from 1 to 2\n", TOWER)).unwrap();
+
+    for crane in ["9000", "9001", "limited:2", "rotating"] {
+        let mut stacks = puzzle.stacks.clone();
+        let result = replay(crane_by_name(crane).unwrap().as_ref(), &mut stacks, &puzzle.commands);
+
+        assert!(
+            matches!(&result, Err(Error::Input(message)) if message == "can't move 5 crates from stack 1, it runs out of crates"),
+            "{} gave {:?}",
+            crane,
+            result
+        );
+    }
+}
+
+#[test]
+fn picks_the_crane_of_part_2() {
+    let mut day05 = Day05::default();
+    day05.configure("crane", Some("limited:2")).unwrap();
+    assert_answers(&day05, EXAMPLE, &["CMZ", "MCZ"]);
+
+    day05.configure("crane", Some("9000")).unwrap();
+    assert_answers(&day05, EXAMPLE, &["CMZ", "CMZ"]);
+
+    for name in ["9002", "limited:0", "limited:x", "limited"] {
+        assert!(matches!(day05.configure("crane", Some(name)), Err(Error::Usage(_))), "accepted `{}`", name);
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_core::testing::assert_answers;
+use aoc_core::{Error, Part, Runnable};
+use day05::{parse_puzzle, Day05};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+fn stacks(drawing: &str) -> Vec<Vec<String>> {
+    parse_puzzle(&format!("{}\n\nmove 1 from 1 to 1\n", drawing)).unwrap().stacks
+}
+
+fn parse_error(drawing: &str) -> (usize, Option<usize>, String) {
+    match parse_puzzle(&format!("{}\n\nmove 1 from 1 to 1\n", drawing)) {
+        Err(Error::Parse { line, column, message }) => (line, column, message),
+        other => panic!("expected a parse error, got {:?}", other.map(|puzzle| puzzle.stacks)),
+    }
+}
+
+#[test]
+fn reads_lines_without_trailing_spaces() {
+    let trimmed: String = EXAMPLE.lines().map(|line| format!("{}\n", line.trim_end())).collect();
+
+    assert_answers(&Day05::default(), &trimmed, &["CMZ", "MCD"]);
+}
+
+#[test]
+fn reads_more_than_nine_stacks() {
+    let drawing = concat!(
+        "                                        [K]\n",
+        "[A] [B] [C] [D] [E] [F] [G] [H] [I] [J] [L]\n",
+        " 1   2   3   4   5   6   7   8   9  10  11",
+    );
+    let stacks = stacks(drawing);
+
+    assert_eq!(stacks.len(), 11);
+    assert_eq!(stacks[9], ["J"]);
+    assert_eq!(stacks[10], ["L", "K"]);
+
+    let input = format!("{}\n\nmove 1 from 11 to 10\nmove 1 from 10 to 1\n", drawing);
+    assert_answers(&Day05::default(), &input, &["KBCDEFGHIJL", "KBCDEFGHIJL"]);
+}
+
+#[test]
+fn reads_crates_with_longer_ids() {
+    let stacks = stacks(concat!(
+        "[X7]\n",
+        "[B2]   [C10]\n",
+        "[A1]    [D]\n",
+        " 1       2",
+    ));
+
+    assert_eq!(stacks, [vec!["A1", "B2", "X7"], vec!["D", "C10"]]);
+}
+
+#[test]
+fn reports_bad_drawings() {
+    assert_eq!(parse_error("[A] [B]\n 1   3"), (2, Some(6), "expected stack label 2, found `3`".to_owned()));
+    assert_eq!(parse_error("[A] [B]\n 1"), (1, Some(5), "crate `[B]` isn't above a single stack label".to_owned()));
+    assert_eq!(parse_error("[A] B\n 1   2"), (1, Some(5), "expected a crate like `[A]`, found `B`".to_owned()));
+    assert_eq!(parse_error("[A] []\n 1   2"), (1, Some(5), "expected a crate like `[A]`, found `[]`".to_owned()));
+    assert_eq!(parse_error("[A][B]\n 1   2"), (1, Some(1), "expected a crate like `[A]`, found `[A][B]`".to_owned()));
+    assert_eq!(parse_error("[ABCDE]\n 1   2"), (1, Some(1), "crate `[ABCDE]` isn't above a single stack label".to_owned()));
+    assert_eq!(parse_error("[A]\n    [B]\n 1   2"), (2, None, "stack 1 has no crate here to hold up those above".to_owned()));
+}
+
+#[test]
+fn needs_a_drawing() {
+    assert!(matches!(
+        Day05::default().run("\nmove 1 from 1 to 2\n", &Part::ALL),
+        Err(Error::Parse { line: 1, .. })
+    ));
+}
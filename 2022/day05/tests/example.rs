@@ -0,0 +1,9 @@
+use aoc_core::testing::assert_answers;
+use day05::Day05;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day05::default(), EXAMPLE, &["CMZ", "MCD"]);
+}
@@ -0,0 +1,137 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+
+pub fn all_unique_chars(v: &VecDeque<char>) -> bool {
+    let mut v1: Vec<char> = v.iter().copied().collect();
+
+    v1.sort();
+    v1.dedup();
+
+    v1.len() == v.len()
+}
+
+/// Returns the number of characters processed before the first window of
+/// `size` distinct characters ends, if there is one.
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    let mut marker = VecDeque::with_capacity(size);
+
+    for (idx, ch) in input.chars().enumerate() {
+        marker.push_back(ch);
+        if idx >= size {
+            marker.pop_front();
+        }
+
+        if idx + 1 >= size && all_unique_chars(&marker) {
+            return Some(idx+1);
+        }
+    }
+
+    None
+}
+
+/// Same as [`find_marker`], but keeps the window as a bitmask of the
+/// characters seen an odd number of times. The window is all distinct
+/// exactly when `size` bits are set.
+///
+/// Only handles ASCII datastreams.
+pub fn find_marker_bitmask(input: &str, size: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let bit = |idx: usize| 1u128 << bytes[idx];
+
+    let mut mask = 0;
+
+    for idx in 0..bytes.len() {
+        mask ^= bit(idx);
+        if idx >= size {
+            mask ^= bit(idx - size);
+        }
+
+        if idx + 1 >= size && mask.count_ones() as usize == size {
+            return Some(idx+1);
+        }
+    }
+
+    None
+}
+
+/// How to check the window of characters for duplicates.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MarkerMethod {
+    /// Sorts a copy of the window and removes duplicates.
+    #[default]
+    Sort,
+    /// Keeps a bitmask of the window, see [`find_marker_bitmask`].
+    Bitmask,
+}
+
+impl FromStr for MarkerMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sort" => Ok(MarkerMethod::Sort),
+            "bitmask" => Ok(MarkerMethod::Bitmask),
+            _ => Err(format!("invalid marker method `{}`, expected sort or bitmask", s)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Day06 {
+    pub marker: MarkerMethod,
+}
+
+impl Day06 {
+    fn find_marker(&self, input: &str, size: usize) -> Option<usize> {
+        match self.marker {
+            MarkerMethod::Sort => find_marker(input, size),
+            MarkerMethod::Bitmask => find_marker_bitmask(input, size),
+        }
+    }
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "marker", value: Some("sort|bitmask"), help: "how to find markers, sort by default" },
+    ];
+
+    type Input = String;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("marker", Some(value)) => self.marker = value.parse().map_err(Error::Usage)?,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let datastream = input.lines()
+            .next()
+            .map(str::to_owned)
+            .ok_or_else(|| Error::Input("the datastream is empty".to_owned()))?;
+
+        if self.marker == MarkerMethod::Bitmask && !datastream.is_ascii() {
+            return Err(Error::Input("the bitmask method only handles ASCII datastreams".to_owned()));
+        }
+
+        Ok(datastream)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        self.find_marker(input, 4)
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("no start-of-packet marker found".to_owned()))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        self.find_marker(input, 14)
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("no start-of-message marker found".to_owned()))
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_core::testing::assert_answers;
+use day06::{find_marker, find_marker_bitmask, Day06, MarkerMethod};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day06::default(), EXAMPLE, &["7", "19"]);
+}
+
+#[test]
+fn more_examples() {
+    assert_answers(&Day06::default(), "bvwbjplbgvbhsrlpgdmjqwftvncz", &["5", "23"]);
+    assert_answers(&Day06::default(), "nppdvjthqldpwncqszvftbrmjlhg", &["6", "23"]);
+    assert_answers(&Day06::default(), "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", &["10", "29"]);
+    assert_answers(&Day06::default(), "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", &["11", "26"]);
+}
+
+#[test]
+fn markers_in_the_first_window() {
+    assert_answers(&Day06::default(), "abcdabcdabcd", &["4"]);
+    assert_answers(&Day06::default(), "abcdefghijklmnopqrst", &["4", "14"]);
+}
+
+#[test]
+fn examples_with_bitmask_markers() {
+    let day06 = Day06 { marker: MarkerMethod::Bitmask };
+
+    assert_answers(&day06, EXAMPLE, &["7", "19"]);
+    assert_answers(&day06, "bvwbjplbgvbhsrlpgdmjqwftvncz", &["5", "23"]);
+    assert_answers(&day06, "nppdvjthqldpwncqszvftbrmjlhg", &["6", "23"]);
+    assert_answers(&day06, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", &["10", "29"]);
+    assert_answers(&day06, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", &["11", "26"]);
+    assert_answers(&day06, "abcdabcdabcd", &["4"]);
+    assert_answers(&day06, "abcdefghijklmnopqrst", &["4", "14"]);
+}
+
+#[test]
+fn bitmask_markers_match_sorted_ones() {
+    let inputs = [EXAMPLE.trim_end(), "abcdabcdabcd", "abc", "", "aaaa", "aabcd", "abcdefghijklmnopqrst"];
+
+    for input in inputs {
+        for size in 1..=14 {
+            assert_eq!(find_marker_bitmask(input, size), find_marker(input, size), "{:?} with size {}", input, size);
+        }
+    }
+}
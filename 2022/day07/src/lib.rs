@@ -0,0 +1,196 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use aoc_core::{Answer, Error, Result, Solution};
+
+#[derive(Debug)]
+pub struct Directory {
+    pub name: String,
+    pub size: u64,
+    pub subdirectories: Vec<Rc<RefCell<Directory>>>,
+    pub files: Vec<File>,
+}
+
+#[derive(Debug)]
+pub struct File {
+    pub name: String,
+    pub size: u64,
+}
+
+impl Directory {
+    pub fn new(name: &str) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            name: name.to_owned(),
+            size: 0,
+            subdirectories: Vec::new(),
+            files: Vec::new(),
+        }))
+    }
+
+    pub fn calculate_size(&mut self) -> u64 {
+        if self.size != 0 {
+            return self.size;
+        }
+
+        for d in &mut self.subdirectories {
+            self.size += d.borrow_mut().calculate_size();
+        }
+
+        for f in &self.files {
+            self.size += f.size;
+        }
+
+        self.size
+    }
+
+    pub fn handle_ls_output_line(&mut self, output_line: &str) -> Result<(), String> {
+        let (size_or_dir, name) = output_line.split_once(' ')
+            .ok_or_else(|| format!("expected `dir <name>` or `<size> <name>`, found `{}`", output_line))?;
+
+        if size_or_dir == "dir" {
+            self.subdirectories.push(Directory::new(name));
+        } else {
+            let size = size_or_dir.parse()
+                .map_err(|_| format!("expected a file size, found `{}`", size_or_dir))?;
+            self.files.push(File::new(name, size));
+        }
+
+        Ok(())
+    }
+
+    pub fn handle_cd(&self, name: &str) -> Option<Rc<RefCell<Directory>>> {
+        for d in &self.subdirectories {
+            if d.borrow().name == name {
+                return Some(d.clone());
+            }
+        }
+        None
+    }
+
+    pub fn find_sum_dirs(&self, at_most: u64) -> u64 {
+        let mut sum_of_sizes: u64 = 0;
+
+        if self.size <= at_most {
+            sum_of_sizes += self.size;
+        }
+
+        for d in &self.subdirectories {
+            sum_of_sizes += d.borrow().find_sum_dirs(at_most);
+        }
+
+        sum_of_sizes
+    }
+
+    pub fn find_smallest_dir_at_least(&self, at_least: u64) -> Option<u64> {
+        let mut smallest_dir_size = None;
+
+        if self.size >= at_least {
+            smallest_dir_size = Some(self.size);
+        }
+
+        for d in &self.subdirectories {
+            let subdir_size = d.borrow().find_smallest_dir_at_least(at_least);
+            if let Some(subdir) = subdir_size {
+                if smallest_dir_size.is_none_or(|smallest| subdir < smallest) {
+                    smallest_dir_size = subdir_size;
+                }
+            }
+        }
+
+        smallest_dir_size
+    }
+}
+
+impl File {
+    pub fn new(name: &str, size: u64) -> Self {
+        Self {
+            name: name.to_owned(),
+            size,
+        }
+    }
+}
+
+pub const TOTAL_SPACE: u64 = 70000000;
+pub const NEEDED_SPACE: u64 = 30000000;
+
+pub fn build_tree(input: &str) -> Result<Rc<RefCell<Directory>>> {
+    let mut directory_stack = Vec::new();
+    let root = Directory::new("/");
+
+    for (idx, input) in input.lines().enumerate() {
+        let line = idx + 1;
+        let outside = || Error::parse(line, "not inside any directory, expected `$ cd /` first");
+
+        if let Some(cmd) = input.strip_prefix("$ ") {
+            if cmd == "ls" {
+                continue; // nothing to do here
+            } else {
+                let dir = cmd.strip_prefix("cd ")
+                    .ok_or_else(|| Error::parse(line, format!("unknown command `{}`", cmd)))?;
+
+                if dir == "/" {
+                    directory_stack.clear();
+                    directory_stack.push(root.clone());
+                } else if dir == ".."{
+                    directory_stack.pop();
+                } else {
+                    let current_dir = directory_stack.last().ok_or_else(outside)?;
+                    let subdir = current_dir.borrow().handle_cd(dir)
+                        .ok_or_else(|| Error::parse(line, format!("no directory `{}` was listed here", dir)))?;
+                    directory_stack.push(subdir);
+                }
+            }
+        } else {
+            // handle ls output
+            let current_dir = directory_stack.last().ok_or_else(outside)?;
+            let mut current_mut_dir = current_dir.borrow_mut();
+            current_mut_dir.handle_ls_output_line(input)
+                .map_err(|message| Error::parse(line, message))?;
+        }
+    }
+
+    root.borrow_mut().calculate_size();
+
+    Ok(root)
+}
+
+/// Returns how much space has to be freed for the update, or `None` if
+/// there is already enough free space.
+pub fn space_to_free(root: &Directory) -> Option<u64> {
+    let free_space = TOTAL_SPACE.saturating_sub(root.size);
+
+    if free_space >= NEEDED_SPACE {
+        None
+    } else {
+        Some(NEEDED_SPACE - free_space)
+    }
+}
+
+#[derive(Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input = Rc<RefCell<Directory>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        build_tree(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.borrow().find_sum_dirs(100000).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let root = input.borrow();
+
+        let at_least = space_to_free(&root)
+            .ok_or_else(|| Error::NoSolution("there is already enough free space".to_owned()))?;
+
+        root.find_smallest_dir_at_least(at_least)
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("no directory is large enough".to_owned()))
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_core::testing::assert_answers;
+use day07::Day07;
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day07, EXAMPLE, &["95437", "24933642"]);
+}
@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use aoc_core::{Answer, Error, Flag, Result, Solution};
+use aoc_grid::{direction, Matrix, ParseError, Pos, Step};
+
+/// Line of sight computations over a forrest of tree heights.
+pub trait Visibility {
+    /// Marks every tree that can be seen from outside the forrest.
+    fn visible_trees(&self) -> Matrix<bool>;
+
+    /// Counts the trees seen from `pos` when looking in the direction of
+    /// `step`, walking outwards one tree at a time.
+    fn viewing_distance(&self, pos: Pos, step: Step) -> u64;
+
+    /// Computes the scenic score of every tree by walking outwards from
+    /// each of them, which is quadratic in the side of the forrest per tree.
+    fn compute_visibility(&self) -> Matrix<u64>;
+
+    /// Computes the same scores as [`Visibility::compute_visibility`] in
+    /// linear time, casting rays along whole rows and columns at once.
+    fn compute_visibility_linear(&self) -> Matrix<u64>;
+}
+
+impl<T: PartialOrd> Visibility for Matrix<T> {
+    fn visible_trees(&self) -> Matrix<bool> {
+        let mut visible = Matrix::filled(self.height(), self.width(), false);
+
+        for step in direction::CARDINAL {
+            for (v, seen) in visible.iter_mut().zip(self.visible_towards(step)) {
+                *v |= seen;
+            }
+        }
+
+        visible
+    }
+
+    fn viewing_distance(&self, pos: Pos, step: Step) -> u64 {
+        let mut distance = 0;
+
+        for (_, tree) in self.walk(pos, step).skip(1) {
+            distance += 1;
+            if *tree >= self[pos] {
+                break;
+            }
+        }
+
+        distance
+    }
+
+    fn compute_visibility(&self) -> Matrix<u64> {
+        let mut v = Matrix::filled(self.height(), self.width(), 0);
+
+        for pos in self.positions() {
+            v[pos] = direction::CARDINAL.iter()
+                .map(|&step| self.viewing_distance(pos, step))
+                .product();
+        }
+
+        v
+    }
+
+    fn compute_visibility_linear(&self) -> Matrix<u64> {
+        let mut v = Matrix::filled(self.height(), self.width(), 1);
+
+        for step in direction::CARDINAL {
+            for (score, sight) in v.iter_mut().zip(self.cast_rays(step)) {
+                *score *= sight.distance as u64;
+            }
+        }
+
+        v
+    }
+}
+
+/// How part 2 computes the scenic scores.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ScenicMethod {
+    /// Monotonic stacks along every row and column, see
+    /// [`Visibility::compute_visibility_linear`].
+    #[default]
+    Stack,
+    /// Walk outwards from every tree, to cross-check the stacks.
+    BruteForce,
+}
+
+impl FromStr for ScenicMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "brute" => Ok(ScenicMethod::BruteForce),
+            "stack" => Ok(ScenicMethod::Stack),
+            _ => Err(format!("invalid scenic method `{}`, expected stack or brute", s)),
+        }
+    }
+}
+
+pub fn parse_forrest(input: &str) -> Result<Matrix<u32>> {
+    let forrest = Matrix::from_text(input, |c| c.to_digit(10))
+        .map_err(|e| match e {
+            ParseError::Ragged { line, expected, found } => {
+                Error::parse(line, format!("expected {} trees, found {}", expected, found))
+            }
+            ParseError::InvalidCell { line, column, found } => {
+                Error::parse_at(line, column, format!("expected a tree height, found `{}`", found))
+            }
+        })?;
+
+    if forrest.is_empty() {
+        return Err(Error::Input("the forrest is empty".to_owned()));
+    }
+
+    Ok(forrest)
+}
+
+pub fn count_visible_trees(forrest: &Matrix<u32>) -> usize {
+    let visible = forrest.visible_trees();
+
+    visible.into_iter().filter(|x| *x).count()
+}
+
+pub fn max_visibility(forrest: &Matrix<u32>, method: ScenicMethod) -> u64 {
+    let visibility = match method {
+        ScenicMethod::BruteForce => forrest.compute_visibility(),
+        ScenicMethod::Stack => forrest.compute_visibility_linear(),
+    };
+
+    visibility.into_iter().max().unwrap()
+}
+
+#[derive(Default)]
+pub struct Day08 {
+    pub scenic: ScenicMethod,
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    const FLAGS: &'static [Flag] = &[
+        Flag { name: "scenic", value: Some("stack|brute"), help: "how to compute scenic scores, stack by default" },
+    ];
+
+    type Input = Matrix<u32>;
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        match (flag, value) {
+            ("scenic", Some(value)) => self.scenic = value.parse().map_err(Error::Usage)?,
+            _ => return Err(Error::Usage(format!("unknown option `--{}`", flag))),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_forrest(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(count_visible_trees(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(max_visibility(input, self.scenic).into())
+    }
+}
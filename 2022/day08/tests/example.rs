@@ -0,0 +1,20 @@
+use aoc_core::testing::assert_answers;
+use day08::{Day08, ScenicMethod};
+
+const EXAMPLE: &str = include_str!("example.txt");
+
+#[test]
+fn example() {
+    assert_answers(&Day08::default(), EXAMPLE, &["21", "8"]);
+}
+
+#[test]
+fn example_with_brute_force_scenic_scores() {
+    assert_answers(&Day08 { scenic: ScenicMethod::BruteForce }, EXAMPLE, &["21", "8"]);
+}
+
+#[test]
+fn scores_with_stacks_by_default() {
+    assert_eq!(Day08::default().scenic, ScenicMethod::Stack);
+    assert_eq!("brute".parse(), Ok(ScenicMethod::BruteForce));
+}
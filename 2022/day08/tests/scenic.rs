@@ -0,0 +1,20 @@
+use aoc_grid::Matrix;
+use day08::Visibility;
+
+#[test]
+fn linear_scenic_scores_match_brute_force() {
+    // Forrests from a small linear congruential generator.
+    let mut state = 0x2022_0008u64;
+    let mut next = |n: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % n
+    };
+
+    for _ in 0..200 {
+        let height = next(30) as usize + 1;
+        let width = next(30) as usize + 1;
+        let forrest = Matrix::from_fn(height, width, |_| next(10) as u32);
+
+        assert_eq!(forrest.compute_visibility_linear(), forrest.compute_visibility(), "\n{}", forrest);
+    }
+}
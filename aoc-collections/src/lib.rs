@@ -0,0 +1,5 @@
+//! Small general purpose collections shared between puzzles.
+
+mod top_k;
+
+pub use top_k::TopK;
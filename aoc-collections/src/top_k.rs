@@ -0,0 +1,116 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Keeps the `k` largest of the items pushed into it.
+///
+/// The items are kept in a min-heap, so every push costs O(log k) and
+/// memory stays O(k) however many items are seen. Of equal items, the
+/// ones pushed first are kept.
+#[derive(Clone, Debug)]
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self { k, heap: BinaryHeap::with_capacity(k) }
+    }
+
+    /// How many items are kept at most.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Offers `item`, returning whether it is now among the kept ones.
+    pub fn push(&mut self, item: T) -> bool {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+            return true;
+        }
+
+        match self.heap.peek_mut() {
+            Some(mut smallest) if item > smallest.0 => {
+                *smallest = Reverse(item);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The smallest kept item, which an item has to beat to get in once
+    /// `k` items are kept.
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(item)| item)
+    }
+
+    /// The kept items in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.heap.iter().map(|Reverse(item)| item)
+    }
+
+    /// The kept items, largest first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting the reversed items ascending puts the largest ones first.
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(item)| item).collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_largest_items() {
+        let mut top = TopK::new(3);
+        top.extend([5, 1, 9, 3, 7, 9, 2]);
+
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.min(), Some(&7));
+        assert_eq!(top.into_sorted_vec(), vec![9, 9, 7]);
+    }
+
+    #[test]
+    fn keeps_everything_below_k() {
+        let mut top = TopK::new(10);
+        top.extend([2, 3, 1]);
+
+        assert_eq!(top.into_sorted_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn keeps_the_first_of_equal_items() {
+        let mut top = TopK::new(2);
+
+        assert!(top.push((4, "first")));
+        assert!(top.push((6, "second")));
+        assert!(!top.push((4, "first")));
+        assert!(top.push((5, "third")));
+        assert_eq!(top.into_sorted_vec(), vec![(6, "second"), (5, "third")]);
+    }
+
+    #[test]
+    fn keeps_nothing_for_zero() {
+        let mut top = TopK::new(0);
+
+        assert!(!top.push(1));
+        assert!(top.is_empty());
+        assert_eq!(top.min(), None);
+    }
+}
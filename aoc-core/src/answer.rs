@@ -0,0 +1,101 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle.
+///
+/// Most puzzles are answered with a number or a short string, but some
+/// days also expose richer results which are kept structured so the CLI
+/// can decide how to present them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    List(Vec<Answer>),
+    Record(Vec<(String, Answer)>),
+}
+
+impl Answer {
+    /// Builds a record from `(field, value)` pairs, keeping their order.
+    pub fn record<K, V, I>(fields: I) -> Self
+    where
+        K: Into<String>,
+        V: Into<Answer>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Answer::Record(fields.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::List(items) => {
+                write!(f, "[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Answer::Record(fields) => {
+                write!(f, "{{")?;
+                for (idx, (name, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(value: Vec<T>) -> Self {
+        Answer::List(value.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,84 @@
+//! Repeated timing of the steps of a solution.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use crate::Part;
+
+/// A separately timed step of solving a puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Summary of the samples timed for one step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    /// 95th percentile, using the nearest rank.
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// If there are no samples.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+
+        samples.sort_unstable();
+
+        let rank = (samples.len() * 95).div_ceil(100);
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[rank - 1],
+        }
+    }
+}
+
+/// The timings of one step over all iterations of a benchmark.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub step: Step,
+    pub stats: Stats,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nanos(values: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        values.into_iter().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn summarizes_unsorted_samples() {
+        let stats = Stats::new(nanos((1..=100).rev()));
+
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(51));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+    }
+
+    #[test]
+    fn single_sample_is_every_statistic() {
+        let stats = Stats::new(nanos([7]));
+        let seven = Duration::from_nanos(7);
+
+        assert_eq!(stats, Stats { min: seven, median: seven, p95: seven });
+    }
+}
@@ -0,0 +1,170 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::process::ExitCode;
+
+use crate::output::write_records;
+use crate::{Error, Flag, Format, Part, Record, Result, Runnable};
+
+/// Help for the options shared by the runner and the per-day binaries.
+pub const OPTIONS_HELP: &str = "    --part <1|2>              run only one part, both are run by default
+    --input <file>            read the puzzle input from a file, `-` for stdin
+    --format <text|json|csv>  how to print the answers, text by default
+    --help                    print this help";
+
+fn option_value<'a>(iter: &mut impl Iterator<Item = &'a String>, option: &str) -> Result<&'a str> {
+    iter.next()
+        .map(String::as_str)
+        .ok_or_else(|| Error::Usage(format!("{} needs a value", option)))
+}
+
+/// Formats the help for day specific `flags`, one per line.
+pub fn flags_help(flags: &[Flag]) -> String {
+    let mut help = String::new();
+
+    for flag in flags {
+        let usage = match flag.value {
+            Some(value) => format!("--{} <{}>", flag.name, value),
+            None => format!("--{}", flag.name),
+        };
+        let _ = writeln!(help, "    {:<25} {}", usage, flag.help);
+    }
+
+    help
+}
+
+/// Options understood by both the `aoc` runner and the per-day binaries.
+#[derive(Debug, Default)]
+pub struct Options {
+    pub parts: Vec<Part>,
+    pub input: Option<String>,
+    pub format: Format,
+    pub help: bool,
+    /// Day specific flags and their values, in command line order.
+    pub flags: Vec<(&'static str, Option<String>)>,
+}
+
+impl Options {
+    /// Parses the shared options and the day specific `flags` out of
+    /// `args`, returning them together with the remaining positional
+    /// arguments.
+    pub fn parse(args: &[String], flags: &[Flag]) -> Result<(Options, Vec<String>)> {
+        let mut options = Options { parts: Part::ALL.to_vec(), ..Default::default() };
+        let mut positional = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--part" => {
+                    options.parts = vec![option_value(&mut iter, arg)?.parse().map_err(Error::Usage)?];
+                }
+                "--input" => {
+                    options.input = Some(option_value(&mut iter, arg)?.to_owned());
+                }
+                "--format" => {
+                    options.format = option_value(&mut iter, arg)?.parse().map_err(Error::Usage)?;
+                }
+                "--help" => options.help = true,
+                _ if arg.starts_with("--") => {
+                    let flag = flags.iter()
+                        .find(|flag| flag.name == &arg[2..])
+                        .ok_or_else(|| Error::Usage(format!("unknown option `{}`", arg)))?;
+                    let value = match flag.value {
+                        Some(_) => Some(option_value(&mut iter, arg)?.to_owned()),
+                        None => None,
+                    };
+                    options.flags.push((flag.name, value));
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        Ok((options, positional))
+    }
+
+    /// Hands the day specific flags over to `solution`.
+    pub fn configure(&self, solution: &mut dyn Runnable) -> Result<()> {
+        for (flag, value) in &self.flags {
+            solution.configure(flag, value.as_deref())?;
+        }
+        Ok(())
+    }
+
+    /// Reads the puzzle input from `--input`, or from stdin if it wasn't
+    /// given or is `-`.
+    pub fn read_input(&self) -> io::Result<String> {
+        match self.input.as_deref() {
+            Some(path) if path != "-" => fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("can't read {}: {}", path, e))),
+            _ => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the puzzle input for streaming, from the same place as
+    /// [`Options::read_input`] reads it.
+    pub fn open_input(&self) -> io::Result<Box<dyn BufRead>> {
+        match self.input.as_deref() {
+            Some(path) if path != "-" => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("can't read {}: {}", path, e))),
+            _ => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+pub fn print_records(format: Format, records: &[Record]) -> Result<()> {
+    write_records(&mut io::stdout().lock(), format, records)?;
+    Ok(())
+}
+
+/// Reports `result` on stderr and turns it into the process exit code.
+pub fn exit(result: Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e @ Error::Usage(_)) => {
+            eprintln!("{}", e);
+            ExitCode::from(e.exit_code())
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+fn run(solution: &mut dyn Runnable, args: &[String]) -> Result<()> {
+    let (options, positional) = Options::parse(args, solution.flags())?;
+
+    if options.help {
+        println!("Usage: day{:02} [options] < input\n\nOptions:\n{}", solution.day(), OPTIONS_HELP);
+        print!("{}", flags_help(solution.flags()));
+        return Ok(());
+    }
+
+    if let Some(arg) = positional.first() {
+        return Err(Error::Usage(format!("unexpected argument `{}`", arg)));
+    }
+
+    options.configure(solution)?;
+
+    let records = if solution.streaming() {
+        solution.run_stream(&mut options.open_input()?, &options.parts)?
+    } else {
+        solution.run(&options.read_input()?, &options.parts)?
+    };
+
+    print_records(options.format, &records)
+}
+
+/// Entry point for the per-day binaries.
+pub fn main(solution: &mut dyn Runnable) -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    exit(run(solution, &args))
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong while running a solution.
+#[derive(Debug)]
+pub enum Error {
+    /// A line of the puzzle input is malformed. Lines and columns are
+    /// counted from 1.
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    /// The puzzle input is malformed as a whole, e.g. it is empty.
+    Input(String),
+    /// The input is well formed, but the puzzle has no answer for it.
+    NoSolution(String),
+    /// The command line arguments are wrong.
+    Usage(String),
+    /// The puzzle input isn't available locally and couldn't be downloaded.
+    Fetch(String),
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Error::Parse { line, column: None, message: message.into() }
+    }
+
+    pub fn parse_at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse { line, column: Some(column), message: message.into() }
+    }
+
+    /// Process exit code for this error, following the BSD `sysexits.h`
+    /// conventions so scripts can tell bad input from bad usage.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Usage(_) => 64,
+            Error::Parse { .. } | Error::Input(_) => 65,
+            Error::Fetch(_) => 69,
+            Error::Io(_) => 74,
+            Error::NoSolution(_) => 1,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { line, column: None, message } => write!(f, "line {}: {}", line, message),
+            Error::Parse { line, column: Some(column), message } => {
+                write!(f, "line {}, column {}: {}", line, column, message)
+            }
+            Error::Input(message) => write!(f, "invalid input: {}", message),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Fetch(message) => write!(f, "{}", message),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
@@ -0,0 +1,206 @@
+mod answer;
+pub mod bench;
+pub mod cli;
+mod error;
+pub mod output;
+pub mod testing;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use output::{Format, Record};
+
+use bench::{Stats, Step, Timing};
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::io::BufRead;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// One of the two halves of a daily puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
+}
+
+/// A command line option specific to one day, e.g. `--top <N>`.
+#[derive(Clone, Copy, Debug)]
+pub struct Flag {
+    pub name: &'static str,
+    /// Placeholder shown for the option's value, or `None` for a switch.
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+/// A solution for a single day of Advent of Code.
+///
+/// The puzzle input is parsed once and then handed to both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Day specific options, passed to [`Solution::configure`].
+    const FLAGS: &'static [Flag] = &[];
+
+    type Input;
+
+    /// Applies one of [`Solution::FLAGS`]. `value` is `None` for switches.
+    fn configure(&mut self, flag: &str, _value: Option<&str>) -> Result<()> {
+        Err(Error::Usage(format!("unknown option `--{}`", flag)))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Whether [`Solution::solve_stream`] should be used instead of reading
+    /// the whole input into memory and parsing it, e.g. because a flag
+    /// asked for it.
+    fn streaming(&self) -> bool {
+        false
+    }
+
+    /// Solves the requested parts in a single pass over `input`, in the
+    /// order they are given.
+    fn solve_stream(&self, _input: &mut dyn BufRead, _parts: &[Part]) -> Result<Vec<Answer>> {
+        Err(Error::Usage(format!("day {} can't stream its input", Self::DAY)))
+    }
+}
+
+/// Object safe view of a [`Solution`], used by the runner to dispatch
+/// to any registered day.
+pub trait Runnable {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn flags(&self) -> &'static [Flag];
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()>;
+
+    /// Parses `input` and solves the requested parts, timing each of them.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<Record>>;
+
+    fn streaming(&self) -> bool;
+
+    /// Solves the requested parts in a single pass over `input`. The parts
+    /// aren't timed separately, each is reported with the time of the pass.
+    fn run_stream(&self, input: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Record>>;
+
+    /// Parses `input` and solves the requested parts `iterations` times,
+    /// timing parsing and every part separately.
+    ///
+    /// # Panics
+    ///
+    /// If `iterations` is zero.
+    fn bench(&self, input: &str, parts: &[Part], iterations: usize) -> Result<Vec<Timing>>;
+}
+
+impl<S: Solution> Runnable for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn flags(&self) -> &'static [Flag] {
+        S::FLAGS
+    }
+
+    fn configure(&mut self, flag: &str, value: Option<&str>) -> Result<()> {
+        Solution::configure(self, flag, value)
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<Record>> {
+        let parsed = self.parse(input)?;
+
+        parts.iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&parsed)?,
+                    Part::Two => self.part2(&parsed)?,
+                };
+                let elapsed = start.elapsed();
+
+                Ok(Record { year: S::YEAR, day: S::DAY, part, answer, elapsed })
+            })
+            .collect()
+    }
+
+    fn streaming(&self) -> bool {
+        Solution::streaming(self)
+    }
+
+    fn run_stream(&self, input: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Record>> {
+        let start = Instant::now();
+        let answers = self.solve_stream(input, parts)?;
+        let elapsed = start.elapsed();
+
+        Ok(parts.iter()
+            .zip(answers)
+            .map(|(&part, answer)| Record { year: S::YEAR, day: S::DAY, part, answer, elapsed })
+            .collect())
+    }
+
+    fn bench(&self, input: &str, parts: &[Part], iterations: usize) -> Result<Vec<Timing>> {
+        assert!(iterations > 0, "benchmarks need at least one iteration");
+
+        let steps: Vec<Step> = [Step::Parse].into_iter()
+            .chain(parts.iter().map(|&part| Step::Part(part)))
+            .collect();
+        let mut samples: Vec<Vec<Duration>> = vec![Vec::with_capacity(iterations); steps.len()];
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let parsed = self.parse(black_box(input))?;
+            samples[0].push(start.elapsed());
+
+            for (&part, part_samples) in parts.iter().zip(&mut samples[1..]) {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&parsed)?,
+                    Part::Two => self.part2(&parsed)?,
+                };
+                part_samples.push(start.elapsed());
+                black_box(answer);
+            }
+        }
+
+        Ok(steps.into_iter()
+            .zip(samples)
+            .map(|(step, samples)| Timing { step, stats: Stats::new(samples) })
+            .collect())
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::Write as _;
+use std::io;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{Answer, Part};
+
+/// The outcome of running one part of one day.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// How records are written to stdout.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format `{}`, expected text, json or csv", s)),
+        }
+    }
+}
+
+fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn push_json_answer(out: &mut String, answer: &Answer) {
+    match answer {
+        Answer::Integer(value) => {
+            let _ = write!(out, "{}", value);
+        }
+        Answer::Text(value) => push_json_string(out, value),
+        Answer::List(items) => {
+            out.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                push_json_answer(out, item);
+            }
+            out.push(']');
+        }
+        Answer::Record(fields) => {
+            out.push('{');
+            for (idx, (name, value)) in fields.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                push_json_string(out, name);
+                out.push(':');
+                push_json_answer(out, value);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Renders an answer as a JSON value.
+pub fn answer_to_json(answer: &Answer) -> String {
+    let mut out = String::new();
+    push_json_answer(&mut out, answer);
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn write_text(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    for r in records {
+        writeln!(out, "{} day {:02} part {}: {} ({:?})", r.year, r.day, r.part, r.answer, r.elapsed)?;
+    }
+    Ok(())
+}
+
+fn write_json(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    let mut json = String::from("[");
+    for (idx, r) in records.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+        let _ = write!(json, "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":", r.year, r.day, r.part);
+        push_json_answer(&mut json, &r.answer);
+        let _ = write!(json, ",\"elapsed_ns\":{}}}", r.elapsed.as_nanos());
+    }
+    json.push(']');
+    writeln!(out, "{}", json)
+}
+
+fn write_csv(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    writeln!(out, "year,day,part,answer,elapsed_ns")?;
+    for r in records {
+        // Structured answers don't fit in a single cell, so they are
+        // embedded as JSON.
+        let answer = match &r.answer {
+            Answer::List(_) | Answer::Record(_) => answer_to_json(&r.answer),
+            answer => answer.to_string(),
+        };
+        writeln!(out, "{},{},{},{},{}", r.year, r.day, r.part, csv_field(&answer), r.elapsed.as_nanos())?;
+    }
+    Ok(())
+}
+
+pub fn write_records(out: &mut impl Write, format: Format, records: &[Record]) -> io::Result<()> {
+    match format {
+        Format::Text => write_text(out, records),
+        Format::Json => write_json(out, records),
+        Format::Csv => write_csv(out, records),
+    }
+}
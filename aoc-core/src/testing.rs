@@ -0,0 +1,31 @@
+//! Helpers for checking solutions against known answers in tests.
+
+use crate::{Part, Result, Runnable};
+
+/// Solves the first `parts` parts of `input` and formats the answers the
+/// way they are printed.
+pub fn answers(solution: &dyn Runnable, input: &str, parts: usize) -> Result<Vec<String>> {
+    let records = solution.run(input, &Part::ALL[..parts])?;
+
+    Ok(records.iter().map(|record| record.answer.to_string()).collect())
+}
+
+/// Asserts that `solution` answers `input` with `expected`, part 1 first.
+/// Only part 1 is checked if `expected` has a single answer.
+///
+/// # Panics
+///
+/// If an answer differs or the solution fails.
+#[track_caller]
+pub fn assert_answers(solution: &dyn Runnable, input: &str, expected: &[&str]) {
+    assert!(
+        (1..=Part::ALL.len()).contains(&expected.len()),
+        "expected answers for 1 or 2 parts, got {}",
+        expected.len()
+    );
+
+    match answers(solution, input, expected.len()) {
+        Ok(found) => assert_eq!(found, expected, "wrong answers for {} day {:02}", solution.year(), solution.day()),
+        Err(e) => panic!("{} day {:02} failed: {}", solution.year(), solution.day(), e),
+    }
+}
@@ -0,0 +1,23 @@
+//! Unit steps for walking over a [`Matrix`](crate::Matrix), as
+//! `(row, column)` deltas.
+
+use crate::Step;
+
+pub const UP: Step = (-1, 0);
+pub const DOWN: Step = (1, 0);
+pub const LEFT: Step = (0, -1);
+pub const RIGHT: Step = (0, 1);
+
+pub const UP_LEFT: Step = (-1, -1);
+pub const UP_RIGHT: Step = (-1, 1);
+pub const DOWN_LEFT: Step = (1, -1);
+pub const DOWN_RIGHT: Step = (1, 1);
+
+/// The 4-connected neighbourhood, clockwise from up.
+pub const CARDINAL: [Step; 4] = [UP, RIGHT, DOWN, LEFT];
+
+/// The diagonal neighbours, clockwise from up-right.
+pub const DIAGONAL: [Step; 4] = [UP_RIGHT, DOWN_RIGHT, DOWN_LEFT, UP_LEFT];
+
+/// The 8-connected neighbourhood, clockwise from up.
+pub const ALL: [Step; 8] = [UP, UP_RIGHT, RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT, LEFT, UP_LEFT];
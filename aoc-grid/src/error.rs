@@ -0,0 +1,36 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Why a block of text couldn't be turned into a [`Matrix`](crate::Matrix).
+///
+/// Lines and columns are counted from 1.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A line has a different number of cells than the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character isn't a valid cell.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Ragged { line, expected, found } => {
+                write!(f, "line {}: expected {} cells, found {}", line, expected, found)
+            }
+            ParseError::InvalidCell { line, column, found } => {
+                write!(f, "line {}, column {}: invalid cell `{}`", line, column, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,61 @@
+use crate::{Matrix, Pos, Step};
+
+/// Walks a straight line through a matrix, see [`Matrix::walk`].
+#[derive(Clone, Debug)]
+pub struct Walk<'a, T> {
+    matrix: &'a Matrix<T>,
+    next: Option<Pos>,
+    step: Step,
+}
+
+impl<'a, T> Walk<'a, T> {
+    pub(crate) fn new(matrix: &'a Matrix<T>, start: Pos, step: Step) -> Self {
+        let next = matrix.contains(start).then_some(start);
+        Self { matrix, next, step }
+    }
+}
+
+impl<'a, T> Iterator for Walk<'a, T> {
+    type Item = (Pos, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.next?;
+
+        self.next = if self.step == (0, 0) {
+            None
+        } else {
+            self.matrix.offset(pos, self.step)
+        };
+
+        Some((pos, &self.matrix[pos]))
+    }
+}
+
+/// The in-bounds neighbours of a cell, see [`Matrix::neighbors4`] and
+/// [`Matrix::neighbors8`].
+#[derive(Clone, Debug)]
+pub struct Neighbors<'a, T> {
+    matrix: &'a Matrix<T>,
+    center: Pos,
+    steps: std::slice::Iter<'static, Step>,
+}
+
+impl<'a, T> Neighbors<'a, T> {
+    pub(crate) fn new(matrix: &'a Matrix<T>, center: Pos, steps: &'static [Step]) -> Self {
+        Self { matrix, center, steps: steps.iter() }
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (Pos, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &step in self.steps.by_ref() {
+            if let Some(pos) = self.matrix.offset(self.center, step) {
+                return Some((pos, &self.matrix[pos]));
+            }
+        }
+
+        None
+    }
+}
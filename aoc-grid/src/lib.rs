@@ -0,0 +1,339 @@
+//! A dense two dimensional grid for puzzles played out on a map.
+
+pub mod direction;
+mod error;
+mod iter;
+mod ray;
+mod view;
+
+pub use error::ParseError;
+pub use iter::{Neighbors, Walk};
+pub use ray::Sight;
+pub use view::View;
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A `(row, column)` position in a matrix.
+pub type Pos = (usize, usize);
+
+/// A `(row, column)` delta between two positions.
+pub type Step = (isize, isize);
+
+/// A matrix of cells stored in row-major order and indexed by
+/// `(row, column)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Matrix<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Matrix<T> {
+    /// Creates an empty 0x0 matrix.
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            width: 0,
+            height: 0,
+        }
+    }
+
+    /// Creates a `height` x `width` matrix from cells in row-major order,
+    /// or returns `None` if there is the wrong number of them.
+    pub fn from_vec(height: usize, width: usize, data: Vec<T>) -> Option<Self> {
+        (data.len() == width * height).then_some(Self { data, width, height })
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+
+        Self { data, width, height }
+    }
+
+    pub fn filled(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `cell`.
+    ///
+    /// All lines must be equally long.
+    pub fn from_text(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut m = Matrix::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let mut width = 0;
+            for (col, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(ParseError::InvalidCell { line: idx + 1, column: col + 1, found: c })?;
+                m.data.push(value);
+                width += 1;
+            }
+
+            if idx == 0 {
+                m.width = width;
+            } else if width != m.width {
+                return Err(ParseError::Ragged { line: idx + 1, expected: m.width, found: width });
+            }
+            m.height += 1;
+        }
+
+        Ok(m)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `step`, or returns `None` if that leaves the matrix.
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): Step) -> Option<Pos> {
+        let pos = (row.checked_add_signed(d_row)?, col.checked_add_signed(d_col)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[self.width * row..self.width * (row + 1)]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.data[self.width * row..self.width * (row + 1)]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks` would panic on a zero width.
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {} out of bounds", col);
+        (0..self.height).map(move |row| &self[(row, col)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Walks from `start` in the direction of `step` until leaving the
+    /// matrix. The start itself is the first item.
+    pub fn walk(&self, start: Pos, step: Step) -> Walk<'_, T> {
+        Walk::new(self, start, step)
+    }
+
+    /// All diagonals running from the top left towards the bottom right,
+    /// starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Walk<'_, T>> {
+        let left = (0..self.height).rev().map(|row| (row, 0));
+        let top = (1..self.width).map(|col| (0, col));
+
+        left.chain(top).map(|start| self.walk(start, direction::DOWN_RIGHT))
+    }
+
+    /// All diagonals running from the top right towards the bottom left,
+    /// starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Walk<'_, T>> {
+        let top = (0..self.width).map(|col| (0, col));
+        let right = (1..self.height).map(|row| (row, self.width.saturating_sub(1)));
+
+        top.chain(right).map(|start| self.walk(start, direction::DOWN_LEFT))
+    }
+
+    /// The up to 4 orthogonally adjacent cells of `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> Neighbors<'_, T> {
+        Neighbors::new(self, pos, &direction::CARDINAL)
+    }
+
+    /// The up to 8 cells surrounding `pos`, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> Neighbors<'_, T> {
+        Neighbors::new(self, pos, &direction::ALL)
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over all cells together with their positions.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// A borrowed `height` x `width` part of the matrix starting at
+    /// `top_left`, or `None` if it doesn't fit.
+    pub fn view(&self, top_left: Pos, height: usize, width: usize) -> Option<View<'_, T>> {
+        let fits = top_left.0 + height <= self.height && top_left.1 + width <= self.width;
+        fits.then(|| View::new(self, top_left, height, width))
+    }
+
+    fn remap(&self, height: usize, width: usize, source: impl Fn(Pos) -> Pos) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_fn(height, width, |pos| self[source(pos)].clone())
+    }
+
+    pub fn transpose(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |(row, col)| (col, row))
+    }
+
+    /// Rotates the matrix by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |(row, col)| (height - 1 - col, row))
+    }
+
+    /// Rotates the matrix by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |(row, col)| (col, width - 1 - row))
+    }
+
+    pub fn rotate_180(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let (height, width) = (self.height, self.width);
+        self.remap(height, width, |(row, col)| (height - 1 - row, width - 1 - col))
+    }
+
+    /// Mirrors the matrix left to right.
+    pub fn flip_horizontal(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |(row, col)| (row, width - 1 - col))
+    }
+
+    /// Mirrors the matrix top to bottom.
+    pub fn flip_vertical(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |(row, col)| (height - 1 - row, col))
+    }
+}
+
+impl Matrix<char> {
+    /// Parses one row of characters per line.
+    pub fn from_chars(text: &str) -> Result<Self, ParseError> {
+        Self::from_text(text, Some)
+    }
+}
+
+impl<T> Default for Matrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<Pos> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &Self::Output {
+        assert!(col < self.width, "column {} out of bounds", col);
+        &self.data[self.width * row + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut Self::Output {
+        assert!(col < self.width, "column {} out of bounds", col);
+        &mut self.data[self.width * row + col]
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            write!(f, "[")?;
+            for value in row {
+                write!(f, " {} ", value)?;
+            }
+            writeln!(f, "]")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//! Line of sight queries along any direction.
+//!
+//! A step vector splits the matrix into parallel lines, and each query
+//! handles every line in a single pass, so casting a ray from every cell
+//! costs O(width * height) per direction instead of walking outwards from
+//! each cell separately.
+
+use crate::{Matrix, Pos, Step, Walk};
+
+/// What a cell sees when looking along a direction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sight {
+    /// Number of cells seen, up to and including the first blocker, or up
+    /// to the edge of the matrix if nothing blocks the view.
+    pub distance: usize,
+    /// Whether the view is blocked by a cell at least as high as the
+    /// viewing one, which is then `distance` steps away.
+    pub blocked: bool,
+}
+
+impl<T> Matrix<T> {
+    /// Splits the matrix into the lines followed by repeatedly moving by
+    /// `step`, each one walked from its first cell.
+    ///
+    /// Every cell belongs to exactly one line.
+    ///
+    /// # Panics
+    ///
+    /// If `step` is `(0, 0)`.
+    pub fn lines(&self, step: Step) -> impl Iterator<Item = Walk<'_, T>> {
+        assert!(step != (0, 0), "lines need a non-zero step");
+
+        let back = (-step.0, -step.1);
+
+        self.positions()
+            .filter(move |&pos| self.offset(pos, back).is_none())
+            .map(move |start| self.walk(start, step))
+    }
+}
+
+impl<T: PartialOrd> Matrix<T> {
+    /// Looks from every cell in the direction of `step` and reports how
+    /// far it can see before a cell at least as high blocks the view.
+    ///
+    /// Each line is scanned once from its far end while keeping a
+    /// monotonic stack of the cells that could still block the view.
+    pub fn cast_rays(&self, step: Step) -> Matrix<Sight> {
+        let mut sights = Matrix::filled(self.height(), self.width(), Sight { distance: 0, blocked: false });
+        let mut line: Vec<(Pos, &T)> = Vec::new();
+        let mut stack: Vec<(usize, &T)> = Vec::new();
+
+        for walk in self.lines(step) {
+            line.clear();
+            line.extend(walk);
+            stack.clear();
+
+            let last = line.len() - 1;
+
+            for (idx, &(pos, value)) in line.iter().enumerate().rev() {
+                while stack.last().is_some_and(|&(_, top)| top < value) {
+                    stack.pop();
+                }
+
+                sights[pos] = match stack.last() {
+                    Some(&(blocker_idx, _)) => Sight { distance: blocker_idx - idx, blocked: true },
+                    None => Sight { distance: last - idx, blocked: false },
+                };
+
+                stack.push((idx, value));
+            }
+        }
+
+        sights
+    }
+
+    /// Marks the cells that are strictly higher than everything in front of
+    /// them in the direction of `step`, i.e. the cells that can be seen from
+    /// outside the matrix when looking back against `step`.
+    pub fn visible_towards(&self, step: Step) -> Matrix<bool> {
+        let mut visible = Matrix::filled(self.height(), self.width(), false);
+
+        let mut line: Vec<(Pos, &T)> = Vec::new();
+
+        for walk in self.lines(step) {
+            line.clear();
+            line.extend(walk);
+
+            let mut highest: Option<&T> = None;
+
+            for &(pos, value) in line.iter().rev() {
+                if highest.is_none_or(|highest| value > highest) {
+                    visible[pos] = true;
+                    highest = Some(value);
+                }
+            }
+        }
+
+        visible
+    }
+}
@@ -0,0 +1,65 @@
+use std::ops::Index;
+
+use crate::{Matrix, Pos};
+
+/// A borrowed rectangular part of a matrix, see [`Matrix::view`].
+///
+/// Positions are relative to the top left corner of the view.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    matrix: &'a Matrix<T>,
+    top_left: Pos,
+    height: usize,
+    width: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub(crate) fn new(matrix: &'a Matrix<T>, top_left: Pos, height: usize, width: usize) -> Self {
+        Self { matrix, top_left, height, width }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&'a T> {
+        if row < self.height && col < self.width {
+            Some(&self.matrix[(self.top_left.0 + row, self.top_left.1 + col)])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        let start = self.top_left.1;
+        &self.matrix.row(self.top_left.0 + row)[start..start + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Iterates over the cells of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_fn(self.height, self.width, |pos| self[pos].clone())
+    }
+}
+
+impl<T> Index<Pos> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).expect("position outside of the view")
+    }
+}
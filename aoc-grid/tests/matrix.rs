@@ -0,0 +1,101 @@
+use aoc_grid::{Matrix, ParseError, Pos, Walk};
+
+fn grid(text: &str) -> Matrix<char> {
+    Matrix::from_chars(text).unwrap()
+}
+
+fn text(matrix: &Matrix<char>) -> String {
+    matrix.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>().join("\n")
+}
+
+fn cells(walk: Walk<'_, char>) -> String {
+    walk.map(|(_, &c)| c).collect()
+}
+
+fn positions<'a>(neighbors: impl Iterator<Item = (Pos, &'a char)>) -> Vec<Pos> {
+    neighbors.map(|(pos, _)| pos).collect()
+}
+
+#[test]
+fn rotates() {
+    let m = grid("abc\ndef");
+
+    assert_eq!(text(&m.rotate_cw()), "da\neb\nfc");
+    assert_eq!(text(&m.rotate_ccw()), "cf\nbe\nad");
+    assert_eq!(text(&m.rotate_180()), "fed\ncba");
+    assert_eq!(m.rotate_cw().rotate_ccw(), m);
+    assert_eq!(m.rotate_cw().rotate_cw(), m.rotate_180());
+    assert_eq!(m.rotate_ccw().rotate_ccw().rotate_ccw(), m.rotate_cw());
+}
+
+#[test]
+fn flips_and_transposes() {
+    let m = grid("abc\ndef");
+
+    assert_eq!(text(&m.flip_horizontal()), "cba\nfed");
+    assert_eq!(text(&m.flip_vertical()), "def\nabc");
+    assert_eq!(text(&m.transpose()), "ad\nbe\ncf");
+    assert_eq!(m.flip_horizontal().flip_vertical(), m.rotate_180());
+}
+
+#[test]
+fn views_part_of_the_matrix() {
+    let m = grid("abcd\nefgh\nijkl");
+    let view = m.view((1, 1), 2, 2).unwrap();
+
+    assert_eq!((view.height(), view.width()), (2, 2));
+    assert_eq!(view.rows().collect::<Vec<_>>(), [['f', 'g'], ['j', 'k']]);
+    assert_eq!(view.iter().collect::<String>(), "fgjk");
+    assert_eq!(view[(1, 0)], 'j');
+    assert_eq!(view.get((0, 1)), Some(&'g'));
+    assert_eq!(view.get((2, 0)), None);
+    assert_eq!(text(&view.to_matrix()), "fg\njk");
+
+    assert_eq!(text(&m.view((1, 3), 2, 1).unwrap().to_matrix()), "h\nl");
+    assert!(m.view((2, 3), 2, 1).is_none());
+    assert!(m.view((0, 0), 3, 5).is_none());
+}
+
+#[test]
+fn walks_diagonals() {
+    let m = grid("abc\ndef");
+
+    assert_eq!(m.diagonals().map(cells).collect::<Vec<_>>(), ["d", "ae", "bf", "c"]);
+    assert_eq!(m.anti_diagonals().map(cells).collect::<Vec<_>>(), ["a", "bd", "ce", "f"]);
+
+    // Every cell is on exactly one diagonal of each kind.
+    let m = grid("abcd\nefgh\nijkl");
+    assert_eq!(m.diagonals().map(|walk| walk.count()).sum::<usize>(), m.len());
+    assert_eq!(m.anti_diagonals().map(|walk| walk.count()).sum::<usize>(), m.len());
+}
+
+#[test]
+fn finds_neighbors_inside_the_matrix() {
+    let m = grid("abc\ndef\nghi");
+
+    assert_eq!(positions(m.neighbors4((1, 1))), [(0, 1), (1, 2), (2, 1), (1, 0)]);
+    assert_eq!(positions(m.neighbors4((0, 0))), [(0, 1), (1, 0)]);
+    assert_eq!(positions(m.neighbors4((2, 2))), [(1, 2), (2, 1)]);
+
+    assert_eq!(m.neighbors8((1, 1)).map(|(_, &c)| c).collect::<String>(), "bcfihgda");
+    assert_eq!(positions(m.neighbors8((0, 0))), [(0, 1), (1, 1), (1, 0)]);
+    assert_eq!(positions(m.neighbors8((2, 1))), [(1, 1), (1, 2), (2, 2), (2, 0), (1, 0)]);
+}
+
+#[test]
+fn reports_bad_text() {
+    assert_eq!(Matrix::from_chars("ab\nab\nabc"), Err(ParseError::Ragged { line: 3, expected: 2, found: 3 }));
+    assert_eq!(
+        Matrix::from_text("12\n3a", |c| c.to_digit(10)),
+        Err(ParseError::InvalidCell { line: 2, column: 2, found: 'a' })
+    );
+
+    assert_eq!(ParseError::Ragged { line: 3, expected: 2, found: 3 }.to_string(), "line 3: expected 2 cells, found 3");
+    assert_eq!(
+        ParseError::InvalidCell { line: 2, column: 2, found: 'a' }.to_string(),
+        "line 2, column 2: invalid cell `a`"
+    );
+
+    let digits = Matrix::from_text("12\n34", |c| c.to_digit(10)).unwrap();
+    assert_eq!(digits.row(1), [3, 4]);
+}
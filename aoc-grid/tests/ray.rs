@@ -0,0 +1,132 @@
+use aoc_grid::{Matrix, Pos, Sight, Step};
+
+fn heights(text: &str) -> Matrix<u32> {
+    Matrix::from_text(text, |c| c.to_digit(10)).unwrap()
+}
+
+fn lines(matrix: &Matrix<u32>, step: Step) -> Vec<Vec<Pos>> {
+    matrix.lines(step).map(|walk| walk.map(|(pos, _)| pos).collect()).collect()
+}
+
+fn sight(distance: usize, blocked: bool) -> Sight {
+    Sight { distance, blocked }
+}
+
+/// Walks from `pos` until something at least as high blocks the view.
+fn look(matrix: &Matrix<u32>, pos: Pos, step: Step) -> Sight {
+    let mut distance = 0;
+    for (_, &height) in matrix.walk(pos, step).skip(1) {
+        distance += 1;
+        if height >= matrix[pos] {
+            return sight(distance, true);
+        }
+    }
+    sight(distance, false)
+}
+
+const GRID: &str = "3037\n2551\n6533";
+
+#[test]
+fn splits_into_lines_along_any_step() {
+    let m = heights(GRID);
+
+    assert_eq!(lines(&m, (1, 1)), [
+        vec![(0, 0), (1, 1), (2, 2)],
+        vec![(0, 1), (1, 2), (2, 3)],
+        vec![(0, 2), (1, 3)],
+        vec![(0, 3)],
+        vec![(1, 0), (2, 1)],
+        vec![(2, 0)],
+    ]);
+    assert_eq!(lines(&m, (0, 2)), [
+        vec![(0, 0), (0, 2)],
+        vec![(0, 1), (0, 3)],
+        vec![(1, 0), (1, 2)],
+        vec![(1, 1), (1, 3)],
+        vec![(2, 0), (2, 2)],
+        vec![(2, 1), (2, 3)],
+    ]);
+    assert_eq!(lines(&m, (1, 2)), [
+        vec![(0, 0), (1, 2)],
+        vec![(0, 1), (1, 3)],
+        vec![(0, 2)],
+        vec![(0, 3)],
+        vec![(1, 0), (2, 2)],
+        vec![(1, 1), (2, 3)],
+        vec![(2, 0)],
+        vec![(2, 1)],
+    ]);
+
+    // Walking against the step starts each line from its other end.
+    let mut reversed = lines(&m, (-1, -2));
+    for line in &mut reversed {
+        line.reverse();
+    }
+    reversed.sort();
+    assert_eq!(reversed, lines(&m, (1, 2)));
+}
+
+#[test]
+#[should_panic(expected = "non-zero step")]
+fn needs_a_step_to_split_into_lines() {
+    let _ = heights(GRID).lines((0, 0)).count();
+}
+
+#[test]
+fn casts_diagonal_rays() {
+    let sights = heights("30373\n25512\n65332\n33549\n35390").cast_rays((1, 1));
+
+    // 3 sees the 5 right next to it, 5 looks over 3 and then sees the 9.
+    assert_eq!(sights[(0, 0)], sight(1, true));
+    assert_eq!(sights[(1, 2)], sight(2, true));
+    // 5 and 6 look over everything up to the edge.
+    assert_eq!(sights[(1, 1)], sight(3, false));
+    assert_eq!(sights[(2, 0)], sight(2, false));
+    assert_eq!(sights[(0, 4)], sight(0, false));
+    assert_eq!(sights[(4, 0)], sight(0, false));
+}
+
+#[test]
+fn casts_rays_with_longer_steps() {
+    let m = heights(GRID);
+
+    let sights = m.cast_rays((0, 2));
+    assert_eq!(sights[(0, 0)], sight(1, true));
+    assert_eq!(sights[(0, 1)], sight(1, true));
+    assert_eq!(sights[(2, 0)], sight(1, false));
+    assert_eq!(sights[(1, 3)], sight(0, false));
+
+    let sights = m.cast_rays((1, 2));
+    assert_eq!(sights[(0, 0)], sight(1, true));
+    assert_eq!(sights[(1, 1)], sight(1, false));
+    assert_eq!(sights[(0, 2)], sight(0, false));
+}
+
+#[test]
+fn sees_from_outside_along_any_step() {
+    let m = heights(GRID);
+
+    let visible = m.visible_towards((0, 2));
+    assert_eq!(visible.row(0), [false, false, true, true]);
+    assert_eq!(visible.row(2), [true, true, true, true]);
+
+    let visible = m.visible_towards((1, 1));
+    assert_eq!(visible.row(0), [false, false, true, true]);
+    assert_eq!(visible.row(1), [false, true, true, true]);
+    assert_eq!(visible.row(2), [true, true, true, true]);
+}
+
+#[test]
+fn casts_rays_like_walking_from_every_cell() {
+    let m = Matrix::from_fn(7, 9, |(row, col)| ((row * 7 + col * 13 + row * col) % 10) as u32);
+
+    for step in [(1, 1), (-1, 1), (0, 2), (1, 2), (-2, -1), (3, 0)] {
+        let sights = m.cast_rays(step);
+        let visible = m.visible_towards(step);
+
+        for pos in m.positions() {
+            assert_eq!(sights[pos], look(&m, pos, step), "from {:?} along {:?}", pos, step);
+            assert_eq!(visible[pos], !look(&m, pos, step).blocked, "from {:?} along {:?}", pos, step);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+
+use crate::Integer;
+
+/// Whether the end of an [`Interval`] is part of it.
+///
+/// Implemented by [`HalfOpen`] and [`Closed`]. Operations work on the
+/// first and last values of intervals, these convert between them and the
+/// stored bounds.
+pub trait Bounds {
+    /// Separates the bounds when debug printing, as in Rust's ranges.
+    const SEPARATOR: &'static str;
+
+    /// The stored bounds of the canonical empty interval.
+    fn empty<T: Integer>() -> (T, T);
+
+    /// The first and last values between `start` and `end`, or `None` if
+    /// there are none.
+    fn first_last<T: Integer>(start: T, end: T) -> Option<(T, T)>;
+
+    /// The bounds to store for the values from `first` to `last`.
+    fn from_first_last<T: Integer>(first: T, last: T) -> (T, T);
+}
+
+/// Bounds of an interval that includes its start but not its end, like
+/// `start..end`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HalfOpen {}
+
+impl Bounds for HalfOpen {
+    const SEPARATOR: &'static str = "..";
+
+    fn empty<T: Integer>() -> (T, T) {
+        (T::ZERO, T::ZERO)
+    }
+
+    fn first_last<T: Integer>(start: T, end: T) -> Option<(T, T)> {
+        (start < end).then(|| (start, end.pred()))
+    }
+
+    fn from_first_last<T: Integer>(first: T, last: T) -> (T, T) {
+        // Operations never go past the values of the intervals they are
+        // given, so `last` is below some end and can't be `MAX`.
+        (first, last.succ())
+    }
+}
+
+/// Bounds of an interval that includes both its start and its end, like
+/// `start..=end`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Closed {}
+
+impl Bounds for Closed {
+    const SEPARATOR: &'static str = "..=";
+
+    fn empty<T: Integer>() -> (T, T) {
+        (T::ONE, T::ZERO)
+    }
+
+    fn first_last<T: Integer>(start: T, end: T) -> Option<(T, T)> {
+        (start <= end).then_some((start, end))
+    }
+
+    fn from_first_last<T: Integer>(first: T, last: T) -> (T, T) {
+        (first, last)
+    }
+}
+
+/// The consecutive integers from `start` to `end`, which is excluded for
+/// [`HalfOpen`] intervals and included for [`Closed`] ones.
+///
+/// Intervals whose end comes before their start are empty. All empty
+/// intervals are stored the same way, so they compare equal.
+pub struct Interval<T, B = HalfOpen> {
+    start: T,
+    end: T,
+    bounds: PhantomData<B>,
+}
+
+/// An interval that includes its end.
+pub type ClosedInterval<T> = Interval<T, Closed>;
+
+impl<T: Integer, B: Bounds> Interval<T, B> {
+    pub fn new(start: T, end: T) -> Self {
+        match B::first_last(start, end) {
+            Some(_) => Self { start, end, bounds: PhantomData },
+            None => Self::empty(),
+        }
+    }
+
+    pub fn empty() -> Self {
+        let (start, end) = B::empty();
+        Self { start, end, bounds: PhantomData }
+    }
+
+    fn from_first_last(first: T, last: T) -> Self {
+        if first > last {
+            return Self::empty();
+        }
+
+        let (start, end) = B::from_first_last(first, last);
+        Self { start, end, bounds: PhantomData }
+    }
+
+    fn first_last(&self) -> Option<(T, T)> {
+        B::first_last(self.start, self.end)
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The smallest value in the interval.
+    pub fn first(&self) -> Option<T> {
+        self.first_last().map(|(first, _)| first)
+    }
+
+    /// The largest value in the interval.
+    pub fn last(&self) -> Option<T> {
+        self.first_last().map(|(_, last)| last)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first_last().is_none()
+    }
+
+    /// How many values are in the interval.
+    ///
+    /// # Panics
+    ///
+    /// If the interval holds every `u128` or `i128`, which are one too many
+    /// to count in a `u128`.
+    pub fn len(&self) -> u128 {
+        match self.first_last() {
+            Some((first, last)) => last.distance_from(first).checked_add(1).expect("interval too long to count"),
+            None => 0,
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.first_last().is_some_and(|(first, last)| first <= value && value <= last)
+    }
+
+    /// Whether every value of `other` is in this interval, which is true for
+    /// an empty `other`.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        match (self.first_last(), other.first_last()) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some((a, b)), Some((c, d))) => a <= c && d <= b,
+        }
+    }
+
+    /// Whether the intervals have a value in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values in both intervals.
+    pub fn intersection(&self, other: &Self) -> Self {
+        match (self.first_last(), other.first_last()) {
+            (Some((a, b)), Some((c, d))) => Self::from_first_last(a.max(c), b.min(d)),
+            _ => Self::empty(),
+        }
+    }
+
+    /// The values in either interval, if they make up an interval, which
+    /// they do when the intervals overlap or one ends right before the
+    /// other starts.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let ((a, b), (c, d)) = match (self.first_last(), other.first_last()) {
+            (None, _) => return Some(*other),
+            (_, None) => return Some(*self),
+            (Some(x), Some(y)) if x <= y => (x, y),
+            (Some(x), Some(y)) => (y, x),
+        };
+
+        // Here `a <= c`, and if `b < c` then `b` can't be `MAX`.
+        (c <= b || b.succ() == c).then(|| Self::from_first_last(a, b.max(d)))
+    }
+
+    /// The values of this interval that aren't in `other`: those below
+    /// `other` and those above it, either of which can be empty.
+    pub fn difference(&self, other: &Self) -> (Self, Self) {
+        let ((a, b), (c, d)) = match (self.first_last(), other.first_last()) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return (*self, Self::empty()),
+        };
+
+        let below = if a < c { Self::from_first_last(a, b.min(c.pred())) } else { Self::empty() };
+        let above = if d < b { Self::from_first_last(a.max(d.succ()), b) } else { Self::empty() };
+
+        (below, above)
+    }
+}
+
+impl<T: Integer> Interval<T, HalfOpen> {
+    pub fn to_closed(self) -> ClosedInterval<T> {
+        match self.first_last() {
+            Some((first, last)) => ClosedInterval::new(first, last),
+            None => ClosedInterval::empty(),
+        }
+    }
+}
+
+impl<T: Integer> Interval<T, Closed> {
+    /// The same values as a half-open interval, unless the interval holds
+    /// `MAX`, which no half-open interval of `T` can.
+    pub fn to_half_open(self) -> Option<Interval<T>> {
+        match self.first_last() {
+            Some((_, last)) if last == T::MAX => None,
+            Some((first, last)) => Some(Interval::new(first, last.succ())),
+            None => Some(Interval::empty()),
+        }
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for ClosedInterval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(start, end)
+    }
+}
+
+// Deriving these would require `B` to implement them too.
+
+impl<T: Copy, B> Clone for Interval<T, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy, B> Copy for Interval<T, B> {}
+
+impl<T: PartialEq, B> PartialEq for Interval<T, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<T: Eq, B> Eq for Interval<T, B> {}
+
+impl<T: std::hash::Hash, B> std::hash::Hash for Interval<T, B> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<T: Integer, B: Bounds> Default for Interval<T, B> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: Debug, B: Bounds> Debug for Interval<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}{}{:?}", self.start, B::SEPARATOR, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn half_open(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end)
+    }
+
+    fn closed(start: i32, end: i32) -> ClosedInterval<i32> {
+        ClosedInterval::new(start, end)
+    }
+
+    #[test]
+    fn measures_intervals() {
+        assert_eq!(half_open(2, 5).len(), 3);
+        assert_eq!(closed(2, 5).len(), 4);
+        assert_eq!(closed(-3, -3).len(), 1);
+        assert_eq!(half_open(5, 2).len(), 0);
+        assert_eq!(ClosedInterval::new(i8::MIN, i8::MAX).len(), 256);
+        assert_eq!(ClosedInterval::new(0, u64::MAX).len(), 1 << 64);
+
+        assert_eq!(half_open(2, 5).last(), Some(4));
+        assert_eq!(closed(2, 5).last(), Some(5));
+        assert_eq!(closed(5, 2).first(), None);
+    }
+
+    #[test]
+    fn stores_empty_intervals_the_same_way() {
+        assert_eq!(half_open(3, 3), half_open(7, -1));
+        assert_eq!(closed(3, 2), ClosedInterval::empty());
+        assert_eq!(format!("{:?}", closed(9, 1)), "1..=0");
+        assert_ne!(closed(3, 3), ClosedInterval::empty());
+    }
+
+    #[test]
+    fn checks_containment() {
+        assert!(closed(2, 8).contains(8));
+        assert!(!half_open(2, 8).contains(8));
+        assert!(closed(2, 8).contains_interval(&closed(3, 7)));
+        assert!(closed(2, 8).contains_interval(&closed(2, 8)));
+        assert!(!closed(2, 8).contains_interval(&closed(3, 9)));
+        assert!(closed(2, 8).contains_interval(&ClosedInterval::empty()));
+        assert!(!ClosedInterval::empty().contains_interval(&closed(2, 2)));
+    }
+
+    #[test]
+    fn intersects() {
+        assert_eq!(closed(2, 6).intersection(&closed(4, 8)), closed(4, 6));
+        assert!(closed(2, 6).overlaps(&closed(6, 8)));
+        assert!(!half_open(2, 6).overlaps(&half_open(6, 8)));
+        assert!(closed(2, 4).intersection(&closed(5, 8)).is_empty());
+    }
+
+    #[test]
+    fn unites_touching_intervals() {
+        assert_eq!(closed(2, 4).union(&closed(5, 8)), Some(closed(2, 8)));
+        assert_eq!(closed(5, 8).union(&closed(2, 4)), Some(closed(2, 8)));
+        assert_eq!(half_open(2, 5).union(&half_open(5, 8)), Some(half_open(2, 8)));
+        assert_eq!(closed(2, 9).union(&closed(4, 5)), Some(closed(2, 9)));
+        assert_eq!(closed(2, 3).union(&closed(5, 8)), None);
+        assert_eq!(closed(5, 2).union(&closed(5, 8)), Some(closed(5, 8)));
+        assert_eq!(ClosedInterval::new(0, u8::MAX).union(&ClosedInterval::new(3, 4)), Some((0..=u8::MAX).into()));
+    }
+
+    #[test]
+    fn subtracts() {
+        assert_eq!(closed(2, 8).difference(&closed(4, 5)), (closed(2, 3), closed(6, 8)));
+        assert_eq!(closed(2, 8).difference(&closed(0, 5)), (closed(1, 0), closed(6, 8)));
+        assert_eq!(closed(2, 8).difference(&closed(5, 9)), (closed(2, 4), ClosedInterval::empty()));
+        assert_eq!(closed(2, 8).difference(&closed(0, 9)), (ClosedInterval::empty(), ClosedInterval::empty()));
+        assert_eq!(closed(2, 8).difference(&closed(10, 12)), (closed(2, 8), ClosedInterval::empty()));
+        assert_eq!(closed(2, 8).difference(&closed(-3, 0)), (ClosedInterval::empty(), closed(2, 8)));
+        assert_eq!(half_open(2, 8).difference(&half_open(4, 5)), (half_open(2, 4), half_open(5, 8)));
+    }
+
+    #[test]
+    fn converts_between_bounds() {
+        assert_eq!(half_open(2, 5).to_closed(), closed(2, 4));
+        assert_eq!(closed(2, 4).to_half_open(), Some(half_open(2, 5)));
+        assert_eq!(closed(2, i32::MAX).to_half_open(), None);
+        assert_eq!(Interval::from(2..5), half_open(2, 5));
+        assert_eq!(format!("{:?} {:?}", half_open(2, 5), closed(2, 4)), "2..5 2..=4");
+    }
+}
@@ -0,0 +1,58 @@
+//! Intervals of integers and sets of them, for puzzles about ranges of
+//! sections, seeds, columns and the like.
+
+mod interval;
+mod set;
+
+pub use interval::{Bounds, Closed, ClosedInterval, HalfOpen, Interval};
+pub use set::IntervalSet;
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// The primitive integer types intervals can be made of.
+pub trait Integer: Copy + Ord + Hash + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next integer, which must not be called on `MAX`.
+    fn succ(self) -> Self;
+
+    /// The previous integer, which must not be called on `MIN`.
+    fn pred(self) -> Self;
+
+    /// How far `self` is from `other`, which must not be smaller. Every
+    /// difference between two integers of the same type fits in a `u128`.
+    fn distance_from(self, other: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn succ(self) -> Self {
+                    self + 1
+                }
+
+                fn pred(self) -> Self {
+                    self - 1
+                }
+
+                fn distance_from(self, other: Self) -> u128 {
+                    // Wrapping around 2^128 gives the exact distance, as it
+                    // is smaller than that, even for negative values.
+                    (self as u128).wrapping_sub(other as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
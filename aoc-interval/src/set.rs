@@ -0,0 +1,239 @@
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+
+use crate::{Bounds, HalfOpen, Integer, Interval};
+
+/// A set of integers, stored as the disjoint intervals it is made of.
+///
+/// Inserted intervals are merged with those they overlap or touch, so the
+/// intervals are kept sorted and never empty, and no two of them could be
+/// merged. Lookups take O(log n) for n intervals.
+pub struct IntervalSet<T, B = HalfOpen> {
+    intervals: Vec<Interval<T, B>>,
+}
+
+impl<T: Integer, B: Bounds> IntervalSet<T, B> {
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// The intervals of the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval<T, B>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T, B>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values are in the set.
+    ///
+    /// # Panics
+    ///
+    /// If there are too many to count in a `u128`.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter()
+            .try_fold(0u128, |len, interval| len.checked_add(interval.len()))
+            .expect("set too large to count")
+    }
+
+    /// The index of the first interval that doesn't end before `value`.
+    fn position(&self, value: T) -> usize {
+        self.intervals.partition_point(|interval| interval.last() < Some(value))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.get(self.position(value)).is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Whether every value of `interval` is in the set.
+    pub fn contains_interval(&self, interval: &Interval<T, B>) -> bool {
+        match interval.first() {
+            Some(first) => self.intervals.get(self.position(first)).is_some_and(|i| i.contains_interval(interval)),
+            None => true,
+        }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T, B>) {
+        let Some(first) = interval.first() else {
+            return;
+        };
+
+        // Intervals ending right before `first` touch the new one and have
+        // to be merged too.
+        let start = self.intervals.partition_point(|i| i.last() < Some(first) && i.union(&interval).is_none());
+        let mut merged = interval;
+        let mut end = start;
+        while let Some(union) = self.intervals.get(end).and_then(|i| i.union(&merged)) {
+            merged = union;
+            end += 1;
+        }
+
+        self.intervals.splice(start..end, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: &Interval<T, B>) {
+        let (Some(first), Some(last)) = (interval.first(), interval.last()) else {
+            return;
+        };
+
+        let start = self.position(first);
+        let end = self.intervals.partition_point(|i| i.first() <= Some(last));
+        let rest: Vec<_> = self.intervals[start..end]
+            .iter()
+            .flat_map(|i| {
+                let (below, above) = i.difference(interval);
+                [below, above]
+            })
+            .filter(|i| !i.is_empty())
+            .collect();
+
+        self.intervals.splice(start..end, rest);
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let common = x.intersection(y);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            // The interval ending first can't overlap anything further on.
+            if x.last() < y.last() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The values of this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(interval);
+        }
+        difference
+    }
+
+    /// The values of `within` that aren't in the set.
+    pub fn gaps(&self, within: Interval<T, B>) -> Self {
+        Self::from_iter([within]).difference(self)
+    }
+}
+
+impl<T: Integer, B: Bounds> Extend<Interval<T, B>> for IntervalSet<T, B> {
+    fn extend<I: IntoIterator<Item = Interval<T, B>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Integer, B: Bounds> FromIterator<Interval<T, B>> for IntervalSet<T, B> {
+    fn from_iter<I: IntoIterator<Item = Interval<T, B>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer, B: Bounds> Default for IntervalSet<T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, B> Clone for IntervalSet<T, B> {
+    fn clone(&self) -> Self {
+        Self { intervals: self.intervals.clone() }
+    }
+}
+
+impl<T: PartialEq, B> PartialEq for IntervalSet<T, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.intervals == other.intervals
+    }
+}
+
+impl<T: Eq, B> Eq for IntervalSet<T, B> {}
+
+impl<T: Debug, B: Bounds> Debug for IntervalSet<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClosedInterval;
+
+    fn set(intervals: &[(u32, u32)]) -> IntervalSet<u32, crate::Closed> {
+        intervals.iter().map(|&(start, end)| ClosedInterval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_intervals() {
+        let set = set(&[(10, 12), (2, 4), (6, 7), (5, 5), (20, 25), (11, 15), (30, 29)]);
+
+        assert_eq!(format!("{:?}", set), "{2..=7, 10..=15, 20..=25}");
+        assert_eq!(set.len(), 18);
+        assert!(set.contains(7) && set.contains(20) && !set.contains(8) && !set.contains(26));
+        assert!(set.contains_interval(&ClosedInterval::new(11, 15)));
+        assert!(!set.contains_interval(&ClosedInterval::new(6, 10)));
+    }
+
+    #[test]
+    fn merges_across_many_intervals() {
+        let mut covered = set(&[(1, 1), (3, 3), (5, 5), (7, 7), (10, 10)]);
+        covered.insert(ClosedInterval::new(2, 8));
+
+        assert_eq!(covered, set(&[(1, 8), (10, 10)]));
+
+        let half_open: IntervalSet<i64> = [Interval::new(0, 5), Interval::new(5, 9)].into_iter().collect();
+        assert_eq!(half_open.intervals(), [Interval::new(0, 9)]);
+    }
+
+    #[test]
+    fn removes_intervals() {
+        let mut covered = set(&[(2, 7), (10, 15), (20, 25)]);
+        covered.remove(&ClosedInterval::new(5, 11));
+        assert_eq!(covered, set(&[(2, 4), (12, 15), (20, 25)]));
+
+        covered.remove(&ClosedInterval::new(13, 13));
+        assert_eq!(covered, set(&[(2, 4), (12, 12), (14, 15), (20, 25)]));
+
+        covered.remove(&ClosedInterval::new(16, 19));
+        covered.remove(&ClosedInterval::empty());
+        assert_eq!(covered, set(&[(2, 4), (12, 12), (14, 15), (20, 25)]));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (18, 30)]);
+
+        assert_eq!(a.union(&b), set(&[(1, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 12), (18, 20)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (13, 17)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (21, 30)]));
+        assert_eq!(a.gaps(ClosedInterval::new(0, 25)), set(&[(0, 0), (6, 9), (21, 25)]));
+    }
+}
@@ -0,0 +1,356 @@
+//! The `aoc bench` subcommand.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::io;
+use std::io::Write;
+use std::time::Duration;
+
+use aoc_core::bench::Timing;
+use aoc_core::cli;
+use aoc_core::cli::Options;
+use aoc_core::{Error, Flag, Format, Part, Result, Runnable};
+
+use crate::input::InputCache;
+use crate::registry;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc bench [<year> [<day>]] [options]
+
+Times parsing and both parts of every selected day, once per registered
+implementation, and compares the alternatives to the default ones.";
+
+const FLAGS: &[Flag] = &[
+    Flag { name: "iterations", value: Some("N"), help: "how many times to run each day, 10 by default" },
+];
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// The timings of one implementation of one day.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub implementation: &'static str,
+    pub timings: Vec<Timing>,
+}
+
+fn usage() -> String {
+    format!("{}\n\nOptions:\n{}\n{}", USAGE, cli::OPTIONS_HELP, cli::flags_help(FLAGS).trim_end())
+}
+
+fn parse_selection(positional: &[String]) -> Result<(Option<u16>, Option<u8>)> {
+    let year = |s: &String| s.parse().map_err(|_| Error::Usage(format!("invalid year `{}`", s)));
+    let day = |s: &String| s.parse().map_err(|_| Error::Usage(format!("invalid day `{}`", s)));
+
+    match positional {
+        [] => Ok((None, None)),
+        [y] => Ok((Some(year(y)?), None)),
+        [y, d] => Ok((Some(year(y)?), Some(day(d)?))),
+        [_, _, arg, ..] => Err(Error::Usage(format!("unexpected argument `{}`", arg))),
+    }
+}
+
+fn parse_iterations(options: &Options) -> Result<usize> {
+    let mut iterations = DEFAULT_ITERATIONS;
+
+    for (_, value) in &options.flags {
+        let value = value.as_deref().unwrap_or_default();
+        iterations = value.parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| Error::Usage(format!("invalid number of iterations `{}`", value)))?;
+    }
+
+    Ok(iterations)
+}
+
+/// The default and alternative implementations of the selected days.
+fn select(year: Option<u16>, day: Option<u8>) -> Vec<(&'static str, Box<dyn Runnable>)> {
+    registry::solutions().into_iter()
+        .map(|solution| ("default", solution))
+        .chain(registry::alternatives())
+        .filter(|(_, s)| year.is_none_or(|year| s.year() == year) && day.is_none_or(|day| s.day() == day))
+        .collect()
+}
+
+/// The days of the implementations, each once and in order. Alternatives
+/// come after all the defaults, so their days aren't next to each other.
+fn days(implementations: &[(&'static str, Box<dyn Runnable>)]) -> Vec<(u16, u8)> {
+    implementations.iter()
+        .map(|(_, s)| (s.year(), s.day()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Times every implementation of `day` on `input`.
+fn bench_day(
+    implementations: &[(&'static str, Box<dyn Runnable>)],
+    (year, day): (u16, u8),
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Entry>> {
+    implementations.iter()
+        .filter(|(_, solution)| (solution.year(), solution.day()) == (year, day))
+        .map(|&(implementation, ref solution)| {
+            let timings = solution.bench(input, parts, iterations)?;
+            Ok(Entry { year, day, implementation, timings })
+        })
+        .collect()
+}
+
+pub fn run(args: &[String]) -> Result<()> {
+    let (options, positional) = Options::parse(args, FLAGS)?;
+
+    if options.help {
+        println!("{}", usage());
+        return Ok(());
+    }
+
+    let (year, day) = parse_selection(&positional)?;
+    let iterations = parse_iterations(&options)?;
+
+    let implementations = select(year, day);
+    let days = days(&implementations);
+
+    if days.is_empty() {
+        return Err(Error::Usage("no solutions match".to_owned()));
+    }
+    if options.input.is_some() && days.len() > 1 {
+        return Err(Error::Usage("--input needs a single day to bench".to_owned()));
+    }
+
+    let cache = InputCache::from_env();
+    let mut entries = Vec::new();
+
+    for (year, day) in days {
+        let input = match options.input {
+            Some(_) => options.read_input()?,
+            None => match cache.load(year, day) {
+                Ok(input) => input,
+                // Only a day asked for by name has to have an input.
+                Err(e) if positional.len() < 2 => {
+                    eprintln!("skipping {} day {:02}: {}", year, day, e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            },
+        };
+
+        entries.extend(bench_day(&implementations, (year, day), &input, &options.parts, iterations)?);
+    }
+
+    write_entries(&mut io::stdout().lock(), options.format, &entries)?;
+    Ok(())
+}
+
+/// How many times slower `timing` is than the same step of the default
+/// implementation of the same day, comparing medians.
+fn relative_to_default(entries: &[Entry], entry: &Entry, timing: &Timing) -> Option<f64> {
+    if entry.implementation == "default" {
+        return None;
+    }
+
+    let default = entries.iter()
+        .find(|e| (e.year, e.day, e.implementation) == (entry.year, entry.day, "default"))?
+        .timings.iter()
+        .find(|t| t.step == timing.step)?;
+
+    Some(timing.stats.median.as_secs_f64() / default.stats.median.as_secs_f64())
+}
+
+fn duration(d: Duration) -> String {
+    format!("{:.1?}", d)
+}
+
+fn write_text(out: &mut impl Write, entries: &[Entry]) -> io::Result<()> {
+    let width = entries.iter()
+        .map(|e| e.implementation.len())
+        .chain(["implementation".len()])
+        .max()
+        .unwrap_or_default();
+
+    writeln!(
+        out,
+        "{:<11}  {:<width$}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "implementation", "step", "min", "median", "p95", "vs default"
+    )?;
+
+    for entry in entries {
+        for timing in &entry.timings {
+            let relative = relative_to_default(entries, entry, timing)
+                .map(|r| format!("{:.2}x", r))
+                .unwrap_or_default();
+
+            let line = format!(
+                "{} day {:02}  {:<width$}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+                entry.year,
+                entry.day,
+                entry.implementation,
+                timing.step.to_string(),
+                duration(timing.stats.min),
+                duration(timing.stats.median),
+                duration(timing.stats.p95),
+                relative
+            );
+            writeln!(out, "{}", line.trim_end())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_json(out: &mut impl Write, entries: &[Entry]) -> io::Result<()> {
+    let mut json = String::from("[");
+    for entry in entries {
+        for timing in &entry.timings {
+            if json.len() > 1 {
+                json.push(',');
+            }
+            let relative = relative_to_default(entries, entry, timing)
+                .map_or_else(|| "null".to_owned(), |r| r.to_string());
+            let _ = write!(
+                json,
+                "{{\"year\":{},\"day\":{},\"implementation\":\"{}\",\"step\":\"{}\",\"min_ns\":{},\"median_ns\":{},\"p95_ns\":{},\"vs_default\":{}}}",
+                entry.year,
+                entry.day,
+                entry.implementation,
+                timing.step,
+                timing.stats.min.as_nanos(),
+                timing.stats.median.as_nanos(),
+                timing.stats.p95.as_nanos(),
+                relative
+            );
+        }
+    }
+    json.push(']');
+    writeln!(out, "{}", json)
+}
+
+fn write_csv(out: &mut impl Write, entries: &[Entry]) -> io::Result<()> {
+    writeln!(out, "year,day,implementation,step,min_ns,median_ns,p95_ns,vs_default")?;
+    for entry in entries {
+        for timing in &entry.timings {
+            let relative = relative_to_default(entries, entry, timing).map(|r| r.to_string()).unwrap_or_default();
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{}",
+                entry.year,
+                entry.day,
+                entry.implementation,
+                timing.step,
+                timing.stats.min.as_nanos(),
+                timing.stats.median.as_nanos(),
+                timing.stats.p95.as_nanos(),
+                relative
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub fn write_entries(out: &mut impl Write, format: Format, entries: &[Entry]) -> io::Result<()> {
+    match format {
+        Format::Text => write_text(out, entries),
+        Format::Json => write_json(out, entries),
+        Format::Csv => write_csv(out, entries),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::Path;
+
+    use aoc_core::bench::{Stats, Step};
+
+    use super::*;
+
+    /// Timings of parsing and part 1 with the given medians, in
+    /// microseconds, and min and p95 one microsecond off.
+    fn entry(day: u8, implementation: &'static str, medians: [u64; 2]) -> Entry {
+        let timings = [Step::Parse, Step::Part(Part::One)].into_iter()
+            .zip(medians)
+            .map(|(step, median)| Timing {
+                step,
+                stats: Stats::new([median - 1, median, median + 1].map(Duration::from_micros).to_vec()),
+            })
+            .collect();
+
+        Entry { year: 2022, day, implementation, timings }
+    }
+
+    fn written(format: Format) -> String {
+        let entries = [entry(6, "default", [10, 40]), entry(6, "bitmask", [20, 10]), entry(7, "default", [5, 5])];
+        let mut out = Vec::new();
+        write_entries(&mut out, format, &entries).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn writes_text_tables() {
+        assert_eq!(written(Format::Text), "\
+day          implementation  step           min      median         p95  vs default
+2022 day 06  default         parse        9.0µs      10.0µs      11.0µs
+2022 day 06  default         part 1      39.0µs      40.0µs      41.0µs
+2022 day 06  bitmask         parse       19.0µs      20.0µs      21.0µs       2.00x
+2022 day 06  bitmask         part 1       9.0µs      10.0µs      11.0µs       0.25x
+2022 day 07  default         parse        4.0µs       5.0µs       6.0µs
+2022 day 07  default         part 1       4.0µs       5.0µs       6.0µs
+");
+    }
+
+    #[test]
+    fn writes_json_records() {
+        let json = written(Format::Json);
+
+        assert!(json.starts_with(
+            "[{\"year\":2022,\"day\":6,\"implementation\":\"default\",\"step\":\"parse\",\
+             \"min_ns\":9000,\"median_ns\":10000,\"p95_ns\":11000,\"vs_default\":null},"
+        ));
+        assert!(json.contains("\"implementation\":\"bitmask\",\"step\":\"parse\",\"min_ns\":19000,\"median_ns\":20000,\"p95_ns\":21000,\"vs_default\":2}"));
+        assert!(json.contains("\"step\":\"part 1\",\"min_ns\":9000,\"median_ns\":10000,\"p95_ns\":11000,\"vs_default\":0.25}"));
+        assert_eq!(json.matches("\"vs_default\":null").count(), 4);
+        assert!(json.ends_with("}]\n"));
+    }
+
+    #[test]
+    fn writes_csv_rows() {
+        assert_eq!(written(Format::Csv), "\
+year,day,implementation,step,min_ns,median_ns,p95_ns,vs_default
+2022,6,default,parse,9000,10000,11000,
+2022,6,default,part 1,39000,40000,41000,
+2022,6,bitmask,parse,19000,20000,21000,2
+2022,6,bitmask,part 1,9000,10000,11000,0.25
+2022,7,default,parse,4000,5000,6000,
+2022,7,default,part 1,4000,5000,6000,
+");
+    }
+
+    #[test]
+    fn benches_every_step_of_every_implementation_once() {
+        let implementations = select(None, None);
+        let days = days(&implementations);
+        assert_eq!(days.len(), registry::solutions().len());
+
+        let mut rows = HashSet::new();
+        for (year, day) in days {
+            let example = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join(format!("../{}/day{:02}/tests/example.txt", year, day));
+            let input = fs::read_to_string(example).unwrap();
+
+            for entry in bench_day(&implementations, (year, day), &input, &Part::ALL, 1).unwrap() {
+                for timing in entry.timings {
+                    let row = (entry.year, entry.day, entry.implementation, timing.step.to_string());
+                    assert!(rows.insert(row.clone()), "{:?} was benched twice", row);
+                }
+            }
+        }
+
+        // Parsing and both parts of every implementation.
+        assert_eq!(rows.len(), implementations.len() * 3);
+    }
+}
@@ -0,0 +1,235 @@
+//! Puzzle inputs, cached on disk and downloaded on demand.
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Error, Result};
+
+/// Somewhere puzzle inputs can be downloaded from.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Downloads inputs from the Advent of Code website, authenticated by the
+/// `session` cookie of a logged in browser.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    const USER_AGENT: &'static str = "github.com/brcha/advent_of_code aoc runner";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new().user_agent(Self::USER_AGENT).build(),
+        }
+    }
+
+    /// Uses the session cookie from `AOC_SESSION`, if it is set.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var("AOC_SESSION").ok()?;
+        Some(Self::new(Self::BASE_URL, session.trim()))
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        let response = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => Error::Fetch(format!("{} answered with HTTP {}", url, code)),
+                e => Error::Fetch(format!("can't download {}: {}", url, e)),
+            })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+/// Puzzle inputs stored as `<dir>/<year>/day<NN>.txt`.
+///
+/// Inputs missing from the cache are downloaded with the fetcher, if there
+/// is one, and stored for the next run, so once an input has been fetched
+/// it keeps working offline.
+pub struct InputCache {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    pub const DEFAULT_DIR: &'static str = "inputs";
+
+    pub fn new(dir: impl Into<PathBuf>, fetcher: Option<Box<dyn Fetcher>>) -> Self {
+        Self { dir: dir.into(), fetcher }
+    }
+
+    /// A cache in `AOC_INPUTS`, or `inputs/` if that isn't set, which
+    /// downloads with the session in `AOC_SESSION`.
+    pub fn from_env() -> Self {
+        let dir = env::var_os("AOC_INPUTS").map_or_else(|| PathBuf::from(Self::DEFAULT_DIR), PathBuf::from);
+        let fetcher = HttpFetcher::from_env().map(|f| Box::new(f) as Box<dyn Fetcher>);
+
+        Self::new(dir, fetcher)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(with_path(e, &path).into()),
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| Error::Fetch(format!(
+            "no cached input at {}, set AOC_SESSION to download it or pass --input",
+            path.display()
+        )))?;
+        let input = fetcher.fetch(year, day)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| with_path(e, parent))?;
+        }
+        fs::write(&path, &input).map_err(|e| with_path(e, &path))?;
+
+        Ok(input)
+    }
+
+    /// Opens the cached input for streaming, downloading it first if it
+    /// isn't cached yet.
+    pub fn open(&self, year: u16, day: u8) -> Result<BufReader<File>> {
+        let path = self.path(year, day);
+
+        if !path.exists() {
+            self.load(year, day)?;
+        }
+
+        let file = File::open(&path).map_err(|e| with_path(e, &path))?;
+        Ok(BufReader::new(file))
+    }
+}
+
+fn with_path(e: io::Error, path: &Path) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use super::*;
+
+    const SESSION: &str = "53cr3t";
+
+    fn temp_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!(
+            "aoc-input-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Stands in for the Advent of Code website: answers `requests` HTTP
+    /// requests with the matching file, provided the session cookie is set.
+    fn serve(files: HashMap<&'static str, &'static str>, requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split_whitespace().nth(1).unwrap_or("").to_owned();
+
+                let mut authorized = false;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    authorized |= header.trim() == format!("Cookie: session={}", SESSION);
+                }
+
+                let (status, body) = match files.get(path.as_str()) {
+                    _ if !authorized => ("400 Bad Request", ""),
+                    Some(body) => ("200 OK", *body),
+                    None => ("404 Not Found", ""),
+                };
+                write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body)
+                    .unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn downloads_missing_inputs_once() {
+        let url = serve(HashMap::from([("/2022/day/6/input", "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")]), 1);
+        let dir = temp_dir();
+        let cache = InputCache::new(&dir, Some(Box::new(HttpFetcher::new(url, SESSION))));
+
+        assert_eq!(cache.load(2022, 6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert!(dir.join("2022").join("day06.txt").is_file());
+
+        // The stand-in server is gone by now, so this has to come from disk.
+        assert_eq!(cache.load(2022, 6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let url = serve(HashMap::new(), 2);
+        let dir = temp_dir();
+
+        let cache = InputCache::new(&dir, Some(Box::new(HttpFetcher::new(&url, SESSION))));
+        assert!(matches!(cache.load(2022, 7), Err(Error::Fetch(message)) if message.ends_with("HTTP 404")));
+
+        let cache = InputCache::new(&dir, Some(Box::new(HttpFetcher::new(&url, "stale"))));
+        assert!(matches!(cache.load(2022, 7), Err(Error::Fetch(message)) if message.ends_with("HTTP 400")));
+
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn works_offline_without_a_fetcher() {
+        let dir = temp_dir();
+        let cache = InputCache::new(&dir, None);
+
+        assert!(matches!(cache.load(2022, 1), Err(Error::Fetch(_))));
+
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        fs::write(cache.path(2022, 1), "1000\n").unwrap();
+        assert_eq!(cache.load(2022, 1).unwrap(), "1000\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+mod bench;
+mod input;
+mod registry;
+
+use std::env;
+use std::process::ExitCode;
+
+use aoc_core::cli;
+use aoc_core::cli::Options;
+use aoc_core::{Error, Result};
+
+use crate::input::InputCache;
+
+const USAGE: &str = "\
+Usage:
+    aoc list
+    aoc run <year> <day> [options]
+    aoc bench [<year> [<day>]] [options]
+
+Without --input, the input is read from inputs/<year>/day<NN>.txt and
+downloaded there first if AOC_SESSION holds an adventofcode.com session
+cookie. AOC_INPUTS overrides the inputs directory.
+
+Use `aoc run <year> <day> --help` to also see the options of that day,
+and `aoc bench --help` for the options of the benchmarks.";
+
+fn usage() -> String {
+    format!("{}\n\nOptions:\n{}", USAGE, cli::OPTIONS_HELP)
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let [year, day, args @ ..] = args else {
+        return Err(Error::Usage(usage()));
+    };
+    let year = year.parse().map_err(|_| Error::Usage(format!("invalid year `{}`", year)))?;
+    let day = day.parse().map_err(|_| Error::Usage(format!("invalid day `{}`", day)))?;
+
+    let mut solution = registry::find(year, day)
+        .ok_or_else(|| Error::Usage(format!("no solution for {} day {}", year, day)))?;
+
+    let (options, positional) = Options::parse(args, solution.flags())?;
+
+    if options.help {
+        println!("{}", usage());
+        print!("{}", cli::flags_help(solution.flags()));
+        return Ok(());
+    }
+
+    if let Some(arg) = positional.first() {
+        return Err(Error::Usage(format!("unexpected argument `{}`", arg)));
+    }
+
+    options.configure(solution.as_mut())?;
+
+    let records = if solution.streaming() {
+        let mut input = match options.input {
+            Some(_) => options.open_input()?,
+            None => Box::new(InputCache::from_env().open(year, day)?),
+        };
+        solution.run_stream(&mut input, &options.parts)?
+    } else {
+        let input = match options.input {
+            Some(_) => options.read_input()?,
+            None => InputCache::from_env().load(year, day)?,
+        };
+        solution.run(&input, &options.parts)?
+    };
+
+    cli::print_records(options.format, &records)
+}
+
+fn list() {
+    for s in registry::solutions() {
+        println!("{} day {:02}", s.year(), s.day());
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench::run(&args[1..]),
+        _ => Err(Error::Usage(usage())),
+    };
+
+    cli::exit(result)
+}
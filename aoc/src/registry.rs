@@ -0,0 +1,67 @@
+use aoc_core::Runnable;
+
+/// Every solution known to the runner, in calendar order.
+pub fn solutions() -> Vec<Box<dyn Runnable>> {
+    vec![
+        Box::new(day01::Day01::default()),
+        Box::new(day02::Day02::default()),
+        Box::new(day03::Day03::default()),
+        Box::new(day04::Day04::default()),
+        Box::new(day05::Day05::default()),
+        Box::new(day06::Day06::default()),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08::default()),
+    ]
+}
+
+/// Alternative implementations of some days, selected through their flags
+/// and named after them, which `aoc bench` compares to the default ones.
+pub fn alternatives() -> Vec<(&'static str, Box<dyn Runnable>)> {
+    vec![
+        ("bitmask", Box::new(day06::Day06 { marker: day06::MarkerMethod::Bitmask })),
+        ("brute", Box::new(day08::Day08 { scenic: day08::ScenicMethod::BruteForce })),
+    ]
+}
+
+pub fn find(year: u16, day: u8) -> Option<Box<dyn Runnable>> {
+    solutions().into_iter()
+        .find(|s| s.year() == year && s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use aoc_core::testing::answers;
+
+    use super::*;
+    use crate::input::InputCache;
+
+    /// Checks every day against the answers registered next to its cached
+    /// input as `inputs/<year>/day<NN>.answers`, one line per part. Days
+    /// without registered answers are skipped, as the real inputs and
+    /// answers can't be committed.
+    #[test]
+    fn real_inputs_match_registered_answers() {
+        let default_dir = || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(InputCache::DEFAULT_DIR);
+        let dir = env::var_os("AOC_INPUTS").map_or_else(default_dir, PathBuf::from);
+        let cache = InputCache::new(dir, None);
+
+        for solution in solutions() {
+            let (year, day) = (solution.year(), solution.day());
+            let Ok(expected) = fs::read_to_string(cache.path(year, day).with_extension("answers")) else {
+                continue;
+            };
+            let expected: Vec<&str> = expected.lines().filter(|line| !line.trim().is_empty()).collect();
+
+            let input = cache.load(year, day)
+                .unwrap_or_else(|e| panic!("{} day {:02} has answers but no input: {}", year, day, e));
+            let found = answers(solution.as_ref(), &input, expected.len())
+                .unwrap_or_else(|e| panic!("{} day {:02} failed: {}", year, day, e));
+
+            assert_eq!(found, expected, "wrong answers for {} day {:02}", year, day);
+        }
+    }
+}